@@ -0,0 +1,498 @@
+//! The module containing the builder used to configure a [`GameLoop`].
+
+use crate::clock::{default_clock, Clock, ClockSource, ManualClock, MonotonicClock};
+#[cfg(feature = "stats")]
+use crate::metrics::Metrics;
+use crate::{
+    interval_from_rate, BackwardTimePolicy, GameLoop, InvariantViolation, Phase, RenderMode,
+    Renderer, ShutdownPolicy, StopHandle, TimestepMode, Updater,
+};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A builder to configure a [`GameLoop`], before handing it the game state.
+///
+/// The default configuration matches the one used by [`GameLoop::new`].
+#[derive(Debug, Clone)]
+pub struct GameLoopBuilder {
+    /// The amount of times per second the game state is updated.
+    updates_per_second: u32,
+
+    /// Whether or not [`GameLoop::run`] renders the final game state before
+    /// returning.
+    render_on_exit: bool,
+
+    /// What [`GameLoop::run`] does with the accumulated time left when it
+    /// stops.
+    shutdown_policy: ShutdownPolicy,
+
+    /// What the game loop does when its clock runs backwards.
+    backward_time_policy: BackwardTimePolicy,
+
+    /// The clock used to measure the time between ticks.
+    clock: Arc<dyn Clock>,
+
+    /// Whether the game loop owns a [`ManualClock`], only advanced through the
+    /// loop itself.
+    manual_clock: bool,
+
+    /// The clock used to pace the renders, if it differs from `clock`.
+    render_clock: Option<Arc<dyn Clock>>,
+
+    /// Whether the game loop runs in deterministic mode.
+    deterministic: bool,
+
+    /// The amount of frames per second the game aims to render, if known.
+    target_frames_per_second: Option<u32>,
+
+    /// Whether the metrics buffers are allocated when the game loop is built.
+    #[cfg(feature = "stats")]
+    preallocate_metrics: bool,
+}
+
+/// A non-fatal warning about a suspicious game loop configuration.
+///
+/// Warnings are advisory: the game loop builds and runs fine regardless, but
+/// the configuration is likely not what was intended. See
+/// [`GameLoopBuilder::warnings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuilderWarning {
+    /// The target frame rate exceeds the update rate, so most frames render
+    /// the same game state, unless the renderer interpolates.
+    RenderTargetExceedsUpdateRate {
+        /// The configured amount of frames per second.
+        frames_per_second: u32,
+
+        /// The configured amount of updates per second.
+        updates_per_second: u32,
+    },
+}
+
+impl fmt::Display for BuilderWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuilderWarning::RenderTargetExceedsUpdateRate {
+                frames_per_second,
+                updates_per_second,
+            } => write!(
+                f,
+                "render target exceeds update rate ({} frames per second, {} updates per second)",
+                frames_per_second, updates_per_second
+            ),
+        }
+    }
+}
+
+impl Default for GameLoopBuilder {
+    fn default() -> Self {
+        Self {
+            updates_per_second: 100,
+            render_on_exit: false,
+            shutdown_policy: ShutdownPolicy::Immediate,
+            backward_time_policy: BackwardTimePolicy::Ignore,
+            clock: default_clock(),
+            manual_clock: false,
+            render_clock: None,
+            deterministic: false,
+            target_frames_per_second: None,
+            #[cfg(feature = "stats")]
+            preallocate_metrics: false,
+        }
+    }
+}
+
+impl GameLoopBuilder {
+    /// Create a new builder with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the amount of times per second the game state is updated.
+    ///
+    /// This is what decouples your game update behaviour from the speed at
+    /// which the game is rendered to the screen (FPS). Defaults to 100.
+    ///
+    /// The update rate can be (much) lower than the frame rate, in which case
+    /// most ticks don't update the game state at all. For example, at one
+    /// update per second, rendering at 60 frames per second, the remainder
+    /// passed to the renderer grows by 1/60th every frame, until the update
+    /// runs, and the remainder starts over from zero. The renderer can use the
+    /// remainder to smooth out what would otherwise be an abrupt transition.
+    ///
+    /// # See Also
+    ///
+    /// * <https://www.koonsolo.com/news/dewitters-gameloop/>
+    /// * <https://gafferongames.com/post/fix_your_timestep/>
+    /// * <http://gameprogrammingpatterns.com/game-loop.html>
+    ///
+    /// # Panics
+    ///
+    /// Building the game loop panics if this is set to zero, and fails if this
+    /// is set to more than a billion, see [`GameLoopBuilder::build`].
+    pub const fn updates_per_second(mut self, updates_per_second: u32) -> Self {
+        self.updates_per_second = updates_per_second;
+        self
+    }
+
+    /// Render the game state one last time when [`GameLoop::run`] stops.
+    ///
+    /// When a stop is requested while the game loop is still draining its
+    /// accumulated time, the last update(s) never make it to the screen. With
+    /// this enabled, the renderer is called once more before `run` returns, if
+    /// the game state was updated since the last render. Defaults to `false`.
+    pub const fn render_on_exit(mut self, render_on_exit: bool) -> Self {
+        self.render_on_exit = render_on_exit;
+        self
+    }
+
+    /// Set what [`GameLoop::run`] does with the accumulated time left when it
+    /// stops.
+    ///
+    /// With [`ShutdownPolicy::DrainUpdates`], the game state is updated until
+    /// the accumulated time is drained, and rendered once more, so that the
+    /// simulation ends on an update boundary, for example to get deterministic
+    /// end states. Defaults to [`ShutdownPolicy::Immediate`].
+    pub const fn shutdown_policy(mut self, shutdown_policy: ShutdownPolicy) -> Self {
+        self.shutdown_policy = shutdown_policy;
+        self
+    }
+
+    /// Set what the game loop does when its clock runs backwards.
+    ///
+    /// A monotonic clock never runs backwards, but a custom clock (see
+    /// [`GameLoopBuilder::clock`]) might. With [`BackwardTimePolicy::Error`],
+    /// the tick measuring the anomaly fails with [`Error::BackwardTime`], so
+    /// that safety-critical simulations can halt. Defaults to
+    /// [`BackwardTimePolicy::Ignore`].
+    ///
+    /// [`Error::BackwardTime`]: crate::Error::BackwardTime
+    pub const fn backward_time_policy(mut self, policy: BackwardTimePolicy) -> Self {
+        self.backward_time_policy = policy;
+        self
+    }
+
+    /// Set the clock used to measure the time between ticks.
+    ///
+    /// Defaults to a [`MonotonicClock`], or a `PerformanceClock` when
+    /// targeting WebAssembly with the `wasm` feature enabled.
+    ///
+    /// [`MonotonicClock`]: crate::MonotonicClock
+    pub fn clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        self.clock = Arc::new(clock);
+        self.manual_clock = false;
+        self
+    }
+
+    /// Set the clock used to measure the time between ticks to one of the
+    /// built-in clock sources.
+    ///
+    /// With [`ClockSource::Manual`], the game loop never reads a real clock.
+    /// Instead, time only passes when it is supplied through
+    /// [`GameLoop::advance`] or [`GameLoop::tick_with_delta`], so that a plain
+    /// [`GameLoop::tick`] doesn't update the game state at all, unless time
+    /// was advanced first.
+    ///
+    /// This overrides any clock set through [`GameLoopBuilder::clock`], and
+    /// vice versa.
+    pub fn clock_source(mut self, source: ClockSource) -> Self {
+        match source {
+            ClockSource::Monotonic => {
+                self.clock = Arc::new(MonotonicClock::default());
+                self.manual_clock = false;
+            }
+            ClockSource::Manual => self.manual_clock = true,
+        }
+        self
+    }
+
+    /// Set a separate clock used to pace the renders.
+    ///
+    /// By default, the clock of the game loop (see [`GameLoopBuilder::clock`])
+    /// drives both the updates, and the pacing of the renders, when limited
+    /// (see [`GameLoop::set_max_render_fps`]). With a render clock, the
+    /// updates follow the clock of the game loop, while the renders follow
+    /// the render clock. For example, a networked client can simulate on the
+    /// clock of the server, while rendering on the clock of the local display.
+    pub fn render_clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        self.render_clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Run the game loop in deterministic mode.
+    ///
+    /// In this mode, the game loop never reads its clock. Instead, every tick
+    /// simulates that exactly one update interval has passed, so that every
+    /// tick runs exactly one update, and one render. This makes the game
+    /// reproducible, for example in CI or when comparing against golden files,
+    /// without having to inject a [`ManualClock`]. Defaults to `false`.
+    ///
+    /// [`ManualClock`]: crate::ManualClock
+    pub const fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Set the amount of frames per second the game aims to render.
+    ///
+    /// The game loop itself doesn't cap the frame rate, this is only used to
+    /// check the configuration for mistakes, see
+    /// [`GameLoopBuilder::warnings`]. Defaults to `None`.
+    pub const fn target_frames_per_second(mut self, frames_per_second: Option<u32>) -> Self {
+        self.target_frames_per_second = frames_per_second;
+        self
+    }
+
+    /// Allocate the buffers holding the metrics when the game loop is built.
+    ///
+    /// By default, the buffers grow as the metrics are collected, which
+    /// allocates during the first ticks of the game. With this enabled, the
+    /// buffers are sized to their full window up front, avoiding allocation
+    /// hitches mid-game. Defaults to `false`.
+    #[cfg(feature = "stats")]
+    pub const fn preallocate_metrics(mut self, preallocate: bool) -> Self {
+        self.preallocate_metrics = preallocate;
+        self
+    }
+
+    /// The non-fatal warnings about the current configuration, if any.
+    ///
+    /// These are advisory, for tools to display, and never prevent the game
+    /// loop from being built.
+    pub fn warnings(&self) -> Vec<BuilderWarning> {
+        let mut warnings = vec![];
+
+        if let Some(frames_per_second) = self.target_frames_per_second {
+            if frames_per_second > self.updates_per_second {
+                warnings.push(BuilderWarning::RenderTargetExceedsUpdateRate {
+                    frames_per_second,
+                    updates_per_second: self.updates_per_second,
+                });
+            }
+        }
+
+        warnings
+    }
+
+    /// The interval between two updates, resolved from the configured amount
+    /// of updates per second.
+    ///
+    /// This allows inspecting the configuration before building the game loop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of updates per second is set to zero.
+    pub fn resolved_interval(&self) -> Duration {
+        interval_from_rate(self.updates_per_second)
+    }
+
+    /// Build the game loop, taking ownership of the game state.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`InvariantViolation::ZeroUpdateInterval`] if the game loop
+    /// is configured with more than a billion updates per second, as the
+    /// update interval would be zero, and ticking would never end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of updates per second is set to zero.
+    pub fn build<T>(self, state: T) -> Result<GameLoop<T>, InvariantViolation>
+    where
+        T: Updater + Renderer,
+    {
+        let update_interval = self.resolved_interval();
+        if update_interval == Duration::default() {
+            return Err(InvariantViolation::ZeroUpdateInterval);
+        }
+
+        // Every game loop gets its own manual clock, so loops built from clones
+        // of the same builder don't share their time.
+        let manual_clock = if self.manual_clock {
+            Some(ManualClock::new())
+        } else {
+            None
+        };
+        let clock: Arc<dyn Clock> = match &manual_clock {
+            Some(manual_clock) => Arc::new(manual_clock.clone()),
+            None => self.clock,
+        };
+
+        #[cfg(feature = "stats")]
+        let metrics = if self.preallocate_metrics {
+            Metrics::preallocated()
+        } else {
+            Metrics::default()
+        };
+
+        Ok(GameLoop {
+            state,
+            previous_tick: None,
+            accumulated_time: Duration::default(),
+            update_interval,
+            render_on_exit: self.render_on_exit,
+            shutdown_policy: self.shutdown_policy,
+            backward_time_policy: self.backward_time_policy,
+            render_pending: false,
+            stop_handle: StopHandle::default(),
+            phase: Phase::Idle,
+            clock,
+            manual_clock,
+            render_clock: self.render_clock,
+            render_clock_read_at: None,
+            max_interpolation: 1.0,
+            deterministic: self.deterministic,
+            paused: false,
+            suspended: false,
+            skip_next_frame_time: false,
+            render_enabled: true,
+            render_every: 1,
+            ticks_since_render: 0,
+            min_render_interval: None,
+            render_time: Duration::default(),
+            suppress_render_errors: Duration::default(),
+            resized_at: None,
+            update_sequence: 0,
+            simulation_time: Duration::default(),
+            auto_pause_after: None,
+            idle_time: Duration::default(),
+            auto_paused: false,
+            label: None,
+            cpu_budget: None,
+            min_frame_time: Duration::default(),
+            max_frame_time: None,
+            timestep_mode: TimestepMode::Fixed,
+            render_mode: RenderMode::Interpolate,
+            forced_frame_time: None,
+            tick_at_reference: None,
+            on_update_boundary: None,
+            on_catch_up_start: None,
+            on_second: None,
+            second_started_at: None,
+            on_catch_up_end: None,
+            catching_up: false,
+            catch_up_streak: 0,
+            render_budget: None,
+            trace_writer: None,
+            first_tick_at: None,
+            update_substeps: 1,
+            #[cfg(feature = "stats")]
+            metrics,
+            max_updates_per_tick: None,
+            max_updates_per_real_second: None,
+            max_accumulated_intervals: None,
+            recent_updates: VecDeque::new(),
+            boost: None,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_resolved_interval() {
+        let builder = GameLoopBuilder::new().updates_per_second(50);
+
+        assert_eq!(builder.resolved_interval(), Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_builder_zero_update_interval() {
+        #[derive(Debug)]
+        struct State;
+
+        impl Updater for State {
+            type Error = std::io::Error;
+
+            fn update(&mut self) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        impl Renderer for State {
+            type Error = std::io::Error;
+
+            fn render(&mut self, _remainder: crate::Remainder) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        let result = GameLoopBuilder::new()
+            .updates_per_second(u32::max_value())
+            .build(State);
+
+        assert_eq!(
+            result.map(|_| ()),
+            Err(InvariantViolation::ZeroUpdateInterval)
+        );
+    }
+
+    #[test]
+    fn test_builder_warnings() {
+        let builder = GameLoopBuilder::new()
+            .updates_per_second(10)
+            .target_frames_per_second(Some(240));
+
+        let warnings = builder.warnings();
+        assert_eq!(
+            warnings,
+            vec![BuilderWarning::RenderTargetExceedsUpdateRate {
+                frames_per_second: 240,
+                updates_per_second: 10,
+            }]
+        );
+        assert!(warnings
+            .first()
+            .map(ToString::to_string)
+            .unwrap_or_default()
+            .starts_with("render target exceeds update rate"));
+
+        assert!(GameLoopBuilder::new().warnings().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_builder_preallocate_metrics() {
+        use crate::metrics::{FRAME_TIME_SAMPLES, MAX_PENDING_REPORTS, PHASE_TIME_SAMPLES};
+        use crate::Remainder;
+
+        #[derive(Debug)]
+        struct State;
+
+        impl Updater for State {
+            type Error = std::io::Error;
+
+            fn update(&mut self) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        impl Renderer for State {
+            type Error = std::io::Error;
+
+            fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        let game_loop = GameLoopBuilder::new()
+            .preallocate_metrics(true)
+            .build(State)
+            .unwrap();
+
+        let (pending, frames, phase_times) = game_loop.metrics.capacities();
+        assert!(pending >= MAX_PENDING_REPORTS);
+        assert!(frames >= FRAME_TIME_SAMPLES);
+        assert!(phase_times >= PHASE_TIME_SAMPLES);
+    }
+}
@@ -0,0 +1,216 @@
+//! The module containing the clocks used by the [`GameLoop`] to measure time.
+//!
+//! [`GameLoop`]: crate::GameLoop
+
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A source of monotonic time.
+///
+/// The game loop only ever looks at the difference between two readings of the
+/// clock, so the point in time the clock starts counting from is irrelevant, as
+/// long as it is fixed for the lifetime of the clock.
+pub trait Clock: Debug + Send + Sync {
+    /// The time passed since the (arbitrary, but fixed) origin of the clock.
+    fn now(&self) -> Duration;
+
+    /// Block the current thread for the given duration, as measured by this
+    /// clock.
+    ///
+    /// The game loop sleeps to stay within its CPU budget, see
+    /// [`GameLoop::set_cpu_budget`]. Defaults to [`std::thread::sleep`].
+    ///
+    /// [`GameLoop::set_cpu_budget`]: crate::GameLoop::set_cpu_budget
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// A source of frame boundaries, such as vsync callbacks, that tells the game
+/// loop how much time passed between two frames.
+///
+/// Some platforms deliver frame timing through events, rather than through a
+/// clock that can be polled. A frame timer decouples the game loop from how
+/// those frame boundaries are produced, see [`GameLoop::run_with_timer`].
+///
+/// [`GameLoop::run_with_timer`]: crate::GameLoop::run_with_timer
+pub trait FrameTimer {
+    /// Block until the next frame is due, returning the time passed since the
+    /// previous frame.
+    fn wait_for_next_frame(&mut self) -> Duration;
+}
+
+/// The built-in clock sources a game loop can be configured with.
+///
+/// See [`GameLoopBuilder::clock_source`].
+///
+/// [`GameLoopBuilder::clock_source`]: crate::GameLoopBuilder::clock_source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockSource {
+    /// A [`MonotonicClock`], backed by [`Instant`].
+    Monotonic,
+
+    /// A clock owned by the game loop, that never moves by itself.
+    ///
+    /// Time only passes when it is supplied to the loop, through
+    /// [`GameLoop::advance`] or [`GameLoop::tick_with_delta`].
+    ///
+    /// [`GameLoop::advance`]: crate::GameLoop::advance
+    /// [`GameLoop::tick_with_delta`]: crate::GameLoop::tick_with_delta
+    Manual,
+}
+
+impl Default for ClockSource {
+    fn default() -> Self {
+        ClockSource::Monotonic
+    }
+}
+
+/// The default clock, backed by [`Instant`].
+#[derive(Debug, Clone, Copy)]
+pub struct MonotonicClock {
+    /// The moment the clock was created, which is the origin of its readings.
+    origin: Instant,
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// A clock that only moves when told to.
+///
+/// This is meant to aid in testing, by fully controlling the time observed by
+/// the game loop. Clones of the clock share the same time, so you can keep a
+/// clone around to advance the time of the clock handed to the game loop.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    /// The current time of the clock, in nanoseconds.
+    nanos: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+    /// Create a new clock, starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward by the given duration.
+    pub fn advance(&self, duration: Duration) {
+        let _ = self.nanos.fetch_add(as_nanos(duration), Ordering::SeqCst);
+    }
+
+    /// Set the clock to the given time.
+    ///
+    /// This can move the clock _backwards_, which never happens with a real
+    /// monotonic clock, but can be used to test how the game loop copes with
+    /// misbehaving clocks.
+    pub fn set(&self, now: Duration) {
+        self.nanos.store(as_nanos(now), Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        Duration::from_nanos(self.nanos.load(Ordering::SeqCst))
+    }
+
+    /// Sleeping on a manual clock returns right away, advancing the clock by
+    /// the given duration instead.
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
+
+/// A clock backed by the browser's `performance.now()`.
+///
+/// [`Instant`] isn't supported on the `wasm32-unknown-unknown` target, so this
+/// clock is used as the default clock when compiling to WebAssembly with the
+/// `wasm` feature enabled.
+///
+/// If the performance API isn't available, the clock stands still.
+#[cfg(feature = "wasm")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PerformanceClock;
+
+#[cfg(feature = "wasm")]
+impl Clock for PerformanceClock {
+    fn now(&self) -> Duration {
+        let millis = web_sys::window()
+            .and_then(|window| window.performance())
+            .map_or(0.0, |performance| performance.now());
+
+        duration_from_millis(millis)
+    }
+
+    /// The browser's main thread can't block, so sleeping does nothing.
+    fn sleep(&self, _duration: Duration) {}
+}
+
+/// The clock used when none is configured explicitly.
+pub(crate) fn default_clock() -> Arc<dyn Clock> {
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    return Arc::new(PerformanceClock);
+
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    Arc::new(MonotonicClock::default())
+}
+
+/// Convert a duration to nanoseconds, saturating at `u64::MAX` (which is more
+/// than 584 years).
+fn as_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::max_value())
+}
+
+/// Convert fractional milliseconds (as returned by `performance.now()`) to a
+/// duration.
+///
+/// Negative (or otherwise invalid) values are treated as zero.
+#[cfg(any(feature = "wasm", test))]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn duration_from_millis(millis: f64) -> Duration {
+    if millis.is_nan() || millis <= 0.0 {
+        return Duration::default();
+    }
+
+    let secs = (millis / 1000.0).trunc();
+    let nanos = ((millis - secs * 1000.0) * 1_000_000.0).round();
+
+    Duration::from_secs(secs as u64) + Duration::from_nanos(nanos as u64)
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_from_millis_increases() {
+        // A mock of consecutive `performance.now()` readings.
+        let readings = [0.0, 0.005, 16.667, 999.999, 1000.0, 61_234.5];
+
+        let durations: Vec<_> = readings.iter().cloned().map(duration_from_millis).collect();
+
+        assert!(durations.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(durations[2], Duration::from_nanos(16_667_000));
+        assert_eq!(durations[5], Duration::from_micros(61_234_500));
+    }
+
+    #[test]
+    fn test_duration_from_invalid_millis() {
+        assert_eq!(duration_from_millis(-1.0), Duration::default());
+        assert_eq!(duration_from_millis(std::f64::NAN), Duration::default());
+    }
+}
@@ -0,0 +1,220 @@
+//! The module containing the plain configuration of a [`GameLoop`].
+//!
+//! [`GameLoop`]: crate::GameLoop
+
+use crate::{interval_from_rate, GameLoopBuilder, Remainder};
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::Duration;
+
+/// The configuration of a game loop, as a plain struct.
+///
+/// This is an alternative to configuring the game loop through a long chain of
+/// [`GameLoopBuilder`] and [`GameLoop`] methods, for example when the
+/// configuration is stored in a file. With the `serde` feature enabled, the
+/// configuration can be (de)serialized, with missing fields taking their
+/// default value. See [`GameLoop::with_config`].
+///
+/// [`GameLoop`]: crate::GameLoop
+/// [`GameLoop::with_config`]: crate::GameLoop::with_config
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LoopConfig {
+    /// See [`GameLoopBuilder::updates_per_second`].
+    pub updates_per_second: u32,
+
+    /// See [`GameLoopBuilder::target_frames_per_second`].
+    pub target_frames_per_second: Option<u32>,
+
+    /// See [`GameLoop::set_max_updates_per_tick`].
+    ///
+    /// [`GameLoop::set_max_updates_per_tick`]: crate::GameLoop::set_max_updates_per_tick
+    pub max_updates_per_tick: Option<usize>,
+
+    /// See [`GameLoop::set_update_substeps`].
+    ///
+    /// [`GameLoop::set_update_substeps`]: crate::GameLoop::set_update_substeps
+    pub update_substeps: usize,
+
+    /// See [`GameLoop::set_max_interpolation`].
+    ///
+    /// [`GameLoop::set_max_interpolation`]: crate::GameLoop::set_max_interpolation
+    pub max_interpolation: Remainder,
+
+    /// See [`GameLoop::set_render_every`].
+    ///
+    /// [`GameLoop::set_render_every`]: crate::GameLoop::set_render_every
+    pub render_every: usize,
+
+    /// See [`GameLoopBuilder::render_on_exit`].
+    pub render_on_exit: bool,
+
+    /// See [`GameLoopBuilder::deterministic`].
+    pub deterministic: bool,
+}
+
+/// A reason a [`LoopConfig`] is invalid.
+///
+/// See [`LoopConfig::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The amount of updates per second is zero, or more than a billion, so
+    /// the update interval can't be represented.
+    InvalidUpdateRate(u32),
+
+    /// The amount of update substeps is zero, or doesn't fit in a `u32`.
+    InvalidUpdateSubsteps(usize),
+
+    /// The game state is rendered every zero ticks.
+    ZeroRenderEvery,
+
+    /// The maximum interpolation is negative, or not a number.
+    InvalidMaxInterpolation,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidUpdateRate(rate) => write!(
+                f,
+                "the update rate must be between one and a billion updates per second, got {}",
+                rate
+            ),
+            ConfigError::InvalidUpdateSubsteps(substeps) => write!(
+                f,
+                "the amount of update substeps must be positive, and fit in a u32, got {}",
+                substeps
+            ),
+            ConfigError::ZeroRenderEvery => {
+                f.write_str("the game loop has to render at least every tick")
+            }
+            ConfigError::InvalidMaxInterpolation => {
+                f.write_str("the maximum interpolation cannot be negative, or not a number")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Default for LoopConfig {
+    fn default() -> Self {
+        Self {
+            updates_per_second: 100,
+            target_frames_per_second: None,
+            max_updates_per_tick: None,
+            update_substeps: 1,
+            max_interpolation: 1.0,
+            render_every: 1,
+            render_on_exit: false,
+            deterministic: false,
+        }
+    }
+}
+
+impl LoopConfig {
+    /// Validate the configuration, as configuring a game loop with an invalid
+    /// configuration would panic, or never finish ticking.
+    ///
+    /// # Errors
+    ///
+    /// Fails with the first invalid field found, see [`ConfigError`].
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.updates_per_second == 0
+            || interval_from_rate(self.updates_per_second) == Duration::default()
+        {
+            return Err(ConfigError::InvalidUpdateRate(self.updates_per_second));
+        }
+
+        if self.update_substeps == 0 || u32::try_from(self.update_substeps).is_err() {
+            return Err(ConfigError::InvalidUpdateSubsteps(self.update_substeps));
+        }
+
+        if self.render_every == 0 {
+            return Err(ConfigError::ZeroRenderEvery);
+        }
+
+        if self.max_interpolation.is_nan() || self.max_interpolation < 0.0 {
+            return Err(ConfigError::InvalidMaxInterpolation);
+        }
+
+        Ok(())
+    }
+
+    /// The builder configured with the parts of the configuration that are
+    /// set before building the game loop.
+    pub(crate) fn builder(&self) -> GameLoopBuilder {
+        GameLoopBuilder::new()
+            .updates_per_second(self.updates_per_second)
+            .target_frames_per_second(self.target_frames_per_second)
+            .render_on_exit(self.render_on_exit)
+            .deterministic(self.deterministic)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{GameLoop, Renderer, Updater};
+
+    #[derive(Debug, Default)]
+    struct State;
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_game_loop_with_invalid_config() {
+        let config = LoopConfig {
+            updates_per_second: u32::max_value(),
+            ..LoopConfig::default()
+        };
+        assert_eq!(
+            GameLoop::with_config(State, &config).map(|_| ()),
+            Err(ConfigError::InvalidUpdateRate(u32::max_value()))
+        );
+
+        let config = LoopConfig {
+            update_substeps: 0,
+            ..LoopConfig::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err(ConfigError::InvalidUpdateSubsteps(0))
+        );
+
+        assert!(LoopConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_game_loop_with_deserialized_config() {
+        let config: LoopConfig = serde_json::from_str(
+            r#"{ "updates_per_second": 50, "max_updates_per_tick": 2, "deterministic": true }"#,
+        )
+        .unwrap();
+        assert_eq!(config.update_substeps, 1);
+
+        let mut game_loop = GameLoop::with_config(State, &config).unwrap();
+        assert_eq!(game_loop.update_interval(), Duration::from_millis(20));
+
+        game_loop.add_accumulated_time(Duration::from_millis(100));
+        let report = game_loop.tick_report().unwrap();
+        assert_eq!(report.updates, 2);
+    }
+}
@@ -0,0 +1,170 @@
+//! The module containing the glue to drive a [`GameLoop`] from an event loop,
+//! such as the one provided by `winit`.
+//!
+//! [`GameLoop`]: crate::GameLoop
+
+#[cfg(feature = "winit")]
+use crate::{Error, GameLoop, Renderer, Updater};
+
+/// The events of a windowing event loop the game loop reacts to.
+///
+/// See [`GameLoop::handle_event`].
+///
+/// [`GameLoop::handle_event`]: crate::GameLoop::handle_event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopEvent {
+    /// All pending events are handled, the game loop ticks.
+    EventsCleared,
+
+    /// The window has to be redrawn, the game state is rendered.
+    RedrawRequested,
+
+    /// The window is asked to close, the game loop stops.
+    CloseRequested,
+
+    /// The window was resized, see [`GameLoop::notify_resized`].
+    ///
+    /// [`GameLoop::notify_resized`]: crate::GameLoop::notify_resized
+    Resized,
+
+    /// Any other event, which the game loop ignores.
+    Ignored,
+}
+
+/// What the event loop should do after handling a [`LoopEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// Keep running the event loop.
+    Continue,
+
+    /// The game loop stopped, exit the event loop.
+    Exit,
+}
+
+#[cfg(feature = "winit")]
+impl<E> From<&winit::event::Event<E>> for LoopEvent {
+    #[allow(clippy::wildcard_enum_match_arm)]
+    fn from(event: &winit::event::Event<E>) -> Self {
+        use winit::event::{Event, WindowEvent};
+
+        match event {
+            Event::MainEventsCleared => LoopEvent::EventsCleared,
+            Event::RedrawRequested(_) => LoopEvent::RedrawRequested,
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                ..
+            } => LoopEvent::CloseRequested,
+            Event::WindowEvent {
+                event: WindowEvent::Resized(_),
+                ..
+            } => LoopEvent::Resized,
+            _ => LoopEvent::Ignored,
+        }
+    }
+}
+
+/// Drive the game loop from a `winit` event loop, until the window is closed,
+/// or a stop is requested through the loop's [`StopHandle`].
+///
+/// The `winit` event loop never returns control, so once the game loop stops,
+/// `on_exit` is called with the game state, or with the error that stopped the
+/// game loop, after which the event loop exits.
+///
+/// [`StopHandle`]: crate::StopHandle
+#[cfg(feature = "winit")]
+pub fn run_winit<T, E, F>(
+    game_loop: GameLoop<T>,
+    event_loop: winit::event_loop::EventLoop<E>,
+    on_exit: F,
+) -> !
+where
+    T: Updater + Renderer + 'static,
+    E: 'static,
+    F: FnOnce(Result<T, Error<T>>) + 'static,
+{
+    use winit::event_loop::ControlFlow;
+
+    let mut game_loop = Some(game_loop);
+    let mut on_exit = Some(on_exit);
+
+    event_loop.run(move |event, _, control_flow| {
+        let result = match game_loop.as_mut() {
+            Some(game_loop) => game_loop.handle_event(LoopEvent::from(&event)),
+            None => return,
+        };
+
+        let result = match result {
+            Ok(Flow::Continue) => {
+                *control_flow = ControlFlow::Poll;
+                return;
+            }
+            Ok(Flow::Exit) => Ok(()),
+            Err(err) => Err(err),
+        };
+
+        *control_flow = ControlFlow::Exit;
+        if let (Some(game_loop), Some(on_exit)) = (game_loop.take(), on_exit.take()) {
+            on_exit(result.map(|()| game_loop.into_inner()));
+        }
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{GameLoop, Remainder, Renderer, Updater};
+
+    #[derive(Debug, Default)]
+    struct State {
+        update: usize,
+        render: usize,
+    }
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.update += 1;
+            Ok(())
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+            self.render += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_handle_event_dispatch() {
+        use LoopEvent::*;
+
+        let mut game_loop = GameLoop::deterministic(State::default());
+        let events = vec![
+            EventsCleared,
+            Ignored,
+            RedrawRequested,
+            EventsCleared,
+            CloseRequested,
+            EventsCleared,
+        ];
+
+        let mut handled = 0;
+        for event in events {
+            handled += 1;
+            if game_loop.handle_event(event).unwrap() == Flow::Exit {
+                break;
+            }
+        }
+
+        // The last event is never handled, because the window was closed.
+        assert_eq!(handled, 5);
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(game_loop.state().render, 3);
+        assert!(game_loop.stop_handle().is_stopped());
+    }
+}
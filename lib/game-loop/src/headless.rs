@@ -0,0 +1,157 @@
+//! The module containing the wrapper to run a game state without rendering it.
+
+use crate::{GameLoop, Remainder, Renderer, Updater};
+use std::any::Any;
+use std::convert::Infallible;
+use std::time::Duration;
+
+/// A wrapper around a game state that only implements [`Updater`], with a
+/// renderer that does nothing.
+///
+/// This allows running update-only game states, such as those of headless
+/// servers, without having to implement a dummy [`Renderer`], see
+/// [`GameLoop::headless`].
+#[derive(Debug, Default, Clone)]
+pub struct Headless<U>
+where
+    U: Updater,
+{
+    /// The wrapped game state.
+    state: U,
+}
+
+impl<U> Headless<U>
+where
+    U: Updater,
+{
+    /// Wrap the given game state.
+    pub fn new(state: U) -> Self {
+        Self { state }
+    }
+
+    /// The wrapped game state.
+    pub fn state(&self) -> &U {
+        &self.state
+    }
+
+    /// The wrapped game state, mutably.
+    pub fn state_mut(&mut self) -> &mut U {
+        &mut self.state
+    }
+
+    /// Unwrap the game state.
+    pub fn into_inner(self) -> U {
+        self.state
+    }
+}
+
+impl<U> Updater for Headless<U>
+where
+    U: Updater,
+{
+    type Error = U::Error;
+
+    fn update(&mut self) -> Result<(), Self::Error> {
+        self.state.update()
+    }
+
+    fn update_step(&mut self, step: Duration) -> Result<(), Self::Error> {
+        self.state.update_step(step)
+    }
+
+    fn update_substep(
+        &mut self,
+        step: Duration,
+        substep: u32,
+        substeps: u32,
+    ) -> Result<(), Self::Error> {
+        self.state.update_substep(step, substep, substeps)
+    }
+
+    fn update_with<C>(&mut self, step: Duration, ctx: &C) -> Result<(), Self::Error>
+    where
+        C: Any,
+    {
+        self.state.update_with(step, ctx)
+    }
+
+    fn on_pause(&mut self) {
+        self.state.on_pause();
+    }
+
+    fn on_resume(&mut self) {
+        self.state.on_resume();
+    }
+
+    fn interpolation_corrected(&self) -> bool {
+        self.state.interpolation_corrected()
+    }
+
+    fn desired_update_rate(&self) -> Option<u32> {
+        self.state.desired_update_rate()
+    }
+
+    fn is_active(&self) -> bool {
+        self.state.is_active()
+    }
+}
+
+impl<U> Renderer for Headless<U>
+where
+    U: Updater,
+{
+    type Error = Infallible;
+
+    fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+// The constructor is only available for headless game states, so it can't be
+// part of the main `impl` block of the game loop.
+#[allow(clippy::multiple_inherent_impl)]
+impl<U> GameLoop<Headless<U>>
+where
+    U: Updater,
+{
+    /// Create a new game loop for a game state that only implements
+    /// [`Updater`], using the default configuration of [`GameLoopBuilder`].
+    ///
+    /// The game state is wrapped in [`Headless`], which renders nothing.
+    ///
+    /// [`GameLoopBuilder`]: crate::GameLoopBuilder
+    pub fn headless(state: U) -> Self {
+        Self::new(Headless::new(state))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Server {
+        update: usize,
+    }
+
+    impl Updater for Server {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.update += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_game_loop_headless() {
+        let mut game_loop = GameLoop::headless(Server::default());
+
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+        let report = game_loop.tick_report().unwrap();
+
+        assert_eq!(report.updates, 2);
+        assert_eq!(game_loop.into_inner().into_inner().update, 2);
+    }
+}
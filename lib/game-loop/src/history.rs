@@ -0,0 +1,321 @@
+//! The module containing the history of game state snapshots, used to render
+//! the game slightly in the past.
+
+use crate::{Blend, Remainder, Renderer, Updater};
+use std::any::Any;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// A wrapper around a game state, that keeps a history of the snapshots of the
+/// last few updates, to render the game slightly in the past.
+///
+/// Where [`Interpolated`] always blends the snapshots of the last two updates,
+/// this wrapper blends the two snapshots straddling the moment to render,
+/// which lies the configured render delay in the past (see
+/// [`History::set_render_delay`]). Rendering in the past trades latency for
+/// smoothness, for example when updates arrive irregularly. Without a render
+/// delay, the game is rendered exactly like [`Interpolated`] would.
+///
+/// Every snapshot is stamped with the simulated time, as passed to
+/// [`Updater::update_step`].
+///
+/// The history only renders snapshots it has: in the
+/// [`RenderMode::Extrapolate`] mode, the render time is one update interval
+/// later, and any render time beyond the newest snapshot renders the newest
+/// snapshot, instead of extrapolating it.
+///
+/// [`RenderMode::Extrapolate`]: crate::RenderMode::Extrapolate
+/// [`Interpolated`]: crate::Interpolated
+#[derive(Debug)]
+pub struct History<S>
+where
+    S: Blend,
+{
+    /// The wrapped game state.
+    state: S,
+
+    /// The snapshots, stamped with the simulated time, oldest first.
+    snapshots: VecDeque<(Duration, S::Snapshot)>,
+
+    /// The maximum amount of snapshots kept around.
+    capacity: usize,
+
+    /// How far in the past the game is rendered.
+    render_delay: Duration,
+
+    /// The last simulated time step, used to stamp snapshots taken after
+    /// [`Updater::update`], which doesn't pass a step.
+    step: Duration,
+}
+
+impl<S> History<S>
+where
+    S: Blend,
+{
+    /// Wrap the given game state, keeping at most `capacity` snapshots.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is less than two, as blending requires at least
+    /// two snapshots.
+    pub fn new(state: S, capacity: usize) -> Self {
+        assert!(
+            capacity >= 2,
+            "the history needs to keep at least two snapshots"
+        );
+
+        let mut snapshots = VecDeque::with_capacity(capacity);
+        snapshots.push_back((Duration::default(), state.snapshot()));
+
+        Self {
+            state,
+            snapshots,
+            capacity,
+            render_delay: Duration::default(),
+            step: Duration::default(),
+        }
+    }
+
+    /// Set how far in the past the game is rendered. Defaults to zero.
+    ///
+    /// The delay should be shorter than the time covered by the history, the
+    /// render time is clamped to the oldest snapshot otherwise.
+    pub fn set_render_delay(&mut self, render_delay: Duration) {
+        self.render_delay = render_delay;
+    }
+
+    /// The wrapped game state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// The wrapped game state, mutably.
+    ///
+    /// Changes made to the game state only show up in the snapshots after the
+    /// next update.
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Unwrap the game state.
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+
+    /// Take a snapshot of the game state, after simulating the given step.
+    fn push_snapshot(&mut self, step: Duration) {
+        let time = self
+            .snapshots
+            .back()
+            .map_or_else(Duration::default, |(time, _)| *time)
+            + step;
+
+        if self.snapshots.len() == self.capacity {
+            let _ = self.snapshots.pop_front();
+        }
+        self.snapshots.push_back((time, self.state.snapshot()));
+    }
+
+    /// The simulated time to render, in nanoseconds.
+    ///
+    /// Like [`Interpolated`], the game is rendered in-between the last two
+    /// updates, based on the remainder, minus the render delay. A remainder of
+    /// `1.0` or more, as passed in the extrapolate render mode, lies beyond the
+    /// newest snapshot, and is clamped to it when rendering.
+    ///
+    /// [`Interpolated`]: crate::Interpolated
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn render_time(&self, remainder: Remainder) -> u128 {
+        let latest = self.snapshots.back().map_or(0, |(time, _)| time.as_nanos());
+        let step = self.step.as_nanos();
+        let offset = (step as Remainder * remainder) as u128;
+
+        (latest.saturating_sub(step) + offset).saturating_sub(self.render_delay.as_nanos())
+    }
+}
+
+impl<S> Updater for History<S>
+where
+    S: Blend + Updater,
+{
+    type Error = <S as Updater>::Error;
+
+    fn update(&mut self) -> Result<(), Self::Error> {
+        self.state.update()?;
+        self.push_snapshot(self.step);
+
+        Ok(())
+    }
+
+    fn update_step(&mut self, step: Duration) -> Result<(), Self::Error> {
+        self.state.update_step(step)?;
+        self.step = step;
+        self.push_snapshot(step);
+
+        Ok(())
+    }
+
+    fn update_substep(
+        &mut self,
+        step: Duration,
+        substep: u32,
+        substeps: u32,
+    ) -> Result<(), Self::Error> {
+        self.state.update_substep(step, substep, substeps)?;
+        if substep + 1 == substeps {
+            self.step = step * substeps;
+            self.push_snapshot(self.step);
+        }
+
+        Ok(())
+    }
+
+    fn update_with<C>(&mut self, step: Duration, ctx: &C) -> Result<(), Self::Error>
+    where
+        C: Any,
+    {
+        self.state.update_with(step, ctx)?;
+        self.step = step;
+        self.push_snapshot(step);
+
+        Ok(())
+    }
+
+    fn on_pause(&mut self) {
+        self.state.on_pause();
+    }
+
+    fn on_resume(&mut self) {
+        self.state.on_resume();
+    }
+
+    fn interpolation_corrected(&self) -> bool {
+        self.state.interpolation_corrected()
+    }
+
+    fn desired_update_rate(&self) -> Option<u32> {
+        self.state.desired_update_rate()
+    }
+
+    fn is_active(&self) -> bool {
+        self.state.is_active()
+    }
+}
+
+impl<S> Renderer for History<S>
+where
+    S: Blend,
+{
+    type Error = <S as Blend>::Error;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn render(&mut self, remainder: Remainder) -> Result<(), Self::Error> {
+        let render_time = self.render_time(remainder);
+
+        // Find the two snapshots straddling the render time, clamping to the
+        // oldest or newest pair of snapshots.
+        let pairs = self.snapshots.iter().zip(self.snapshots.iter().skip(1));
+        let mut straddling = None;
+        for (previous, current) in pairs {
+            straddling = Some((previous, current));
+            if current.0.as_nanos() >= render_time {
+                break;
+            }
+        }
+
+        let frame = if let Some(((previous_time, previous), (current_time, current))) = straddling {
+            let start = previous_time.as_nanos();
+            let span = current_time.as_nanos().saturating_sub(start);
+            let fraction = if span == 0 {
+                1.0
+            } else {
+                let elapsed = render_time.saturating_sub(start).min(span);
+                elapsed as Remainder / span as Remainder
+            };
+
+            S::blend(previous, current, fraction)
+        } else {
+            let snapshot = self.state.snapshot();
+            S::blend(&snapshot, &snapshot, 0.0)
+        };
+
+        self.state.render_blended(frame)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{GameLoop, RenderMode};
+
+    #[derive(Debug, Default)]
+    struct State {
+        position: i32,
+        rendered: Vec<i32>,
+    }
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.position += 10;
+            Ok(())
+        }
+    }
+
+    impl Blend for State {
+        type Snapshot = i32;
+        type Frame = i32;
+        type Error = std::io::Error;
+
+        fn snapshot(&self) -> Self::Snapshot {
+            self.position
+        }
+
+        #[allow(
+            clippy::cast_lossless,
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss
+        )]
+        fn blend(previous: &i32, current: &i32, remainder: Remainder) -> i32 {
+            previous + ((current - previous) as Remainder * remainder).round() as i32
+        }
+
+        fn render_blended(&mut self, frame: i32) -> Result<(), Self::Error> {
+            self.rendered.push(frame);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_history_renders_delayed_snapshots() {
+        let mut history = History::new(State::default(), 4);
+        history.set_render_delay(Duration::from_millis(12));
+        let mut game_loop = GameLoop::new(history);
+
+        // Three updates take snapshots at 10, 20 and 30 milliseconds, and leave
+        // half an update interval. Without a delay, the game would be rendered
+        // at 25 milliseconds, with the delay, it's rendered at 13 milliseconds,
+        // in-between the first two updates.
+        game_loop.add_accumulated_time(Duration::from_millis(35));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().state().rendered, vec![13]);
+    }
+
+    #[test]
+    fn test_history_clamps_extrapolation() {
+        let mut game_loop = GameLoop::new(History::new(State::default(), 4));
+        game_loop.set_render_mode(RenderMode::Extrapolate);
+
+        game_loop.add_accumulated_time(Duration::from_millis(35));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().state().rendered, vec![30]);
+    }
+}
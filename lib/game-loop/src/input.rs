@@ -0,0 +1,137 @@
+//! The module containing the adapter to feed sampled input into a
+//! [`GameLoop`], once per tick.
+
+use crate::{Error, GameLoop, Renderer, Updater};
+use std::any::Any;
+
+/// A source of input, such as a gamepad or keyboard library, that can be
+/// sampled once per tick.
+pub trait InputSource {
+    /// A snapshot of the input, passed to the game state as the tick context.
+    type Input: Any;
+
+    /// Sample the current state of the input.
+    fn poll(&mut self) -> Self::Input;
+}
+
+/// An adapter that samples an [`InputSource`] once per tick, and threads the
+/// sampled input through [`GameLoop::tick_with_context`].
+///
+/// The game state receives the input in [`Updater::update_with`] (and
+/// [`Renderer::render_with`]), and can downcast the context to
+/// [`InputSource::Input`] to read it. Sampling once per tick means every
+/// update of a tick sees the same input, even while catching up.
+#[derive(Debug, Clone, Default)]
+pub struct InputContext<S>
+where
+    S: InputSource,
+{
+    /// The sampled input source.
+    source: S,
+}
+
+impl<S> InputContext<S>
+where
+    S: InputSource,
+{
+    /// Create a new adapter, sampling the given input source.
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// The sampled input source.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// The sampled input source, mutably.
+    pub fn source_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    /// Sample the input source, and run a single tick of the given game loop
+    /// with the sampled input as its context.
+    pub fn tick<T>(&mut self, game_loop: &mut GameLoop<T>) -> Result<(), Error<T>>
+    where
+        T: Updater + Renderer,
+    {
+        let input = self.source.poll();
+
+        game_loop.tick_with_context(&input)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{GameLoop, Remainder};
+    use std::time::Duration;
+
+    /// A fake gamepad, pressing the button every other poll.
+    #[derive(Debug, Default)]
+    struct Gamepad {
+        polls: usize,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Buttons {
+        south: bool,
+    }
+
+    impl InputSource for Gamepad {
+        type Input = Buttons;
+
+        fn poll(&mut self) -> Buttons {
+            self.polls += 1;
+            Buttons {
+                south: self.polls % 2 == 0,
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct State {
+        pressed: Vec<bool>,
+    }
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn update_with<C: Any>(&mut self, _step: Duration, ctx: &C) -> Result<(), Self::Error> {
+            let ctx: &dyn Any = ctx;
+            if let Some(buttons) = ctx.downcast_ref::<Buttons>() {
+                self.pressed.push(buttons.south);
+            }
+            self.update()
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_input_context_reaches_update_with() {
+        let mut input = InputContext::new(Gamepad::default());
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        input.tick(&mut game_loop).unwrap();
+
+        // Both updates of a catching-up tick see the same sampled input.
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+        input.tick(&mut game_loop).unwrap();
+
+        assert_eq!(game_loop.state().pressed, vec![false, true, true]);
+        assert_eq!(input.source().polls, 2);
+    }
+}
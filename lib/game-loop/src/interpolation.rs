@@ -0,0 +1,347 @@
+//! The module containing the helpers to interpolate between game states.
+
+use crate::{Remainder, Renderer, Updater, NANOSECONDS_PER_SECOND};
+use std::any::Any;
+use std::fmt::Debug;
+use std::mem;
+use std::time::Duration;
+
+/// A game state that is rendered by blending two snapshots of itself.
+///
+/// As described in the documentation of the [`Renderer`], the game is rendered
+/// in-between two game updates. To interpolate between those two updates, the
+/// previous state of the game has to be kept around, which is what the
+/// [`Interpolated`] wrapper takes care of, using the snapshots produced by this
+/// trait.
+pub trait Blend: Debug {
+    /// A snapshot of the game state, taken after every update.
+    type Snapshot: Clone + Debug;
+
+    /// The frame produced by blending two snapshots, which is rendered.
+    type Frame;
+
+    /// The error type returned when rendering fails.
+    type Error: std::error::Error;
+
+    /// Take a snapshot of the current game state.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Blend the snapshots of the previous and current update into the frame
+    /// to render, based on the `remainder` between the two updates.
+    fn blend(
+        previous: &Self::Snapshot,
+        current: &Self::Snapshot,
+        remainder: Remainder,
+    ) -> Self::Frame;
+
+    /// Render the blended frame.
+    fn render_blended(&mut self, frame: Self::Frame) -> Result<(), Self::Error>;
+}
+
+/// A wrapper around a game state, that keeps track of the snapshots of the
+/// last two updates, to render the blend of the two.
+///
+/// The wrapper implements [`Updater`] (if the wrapped state does) and
+/// [`Renderer`], so it can be handed to the game loop in place of the state.
+///
+/// A snapshot is taken after every update. When updates are divided into
+/// substeps (see [`Updater::update_substep`]), the snapshot is taken after the
+/// last substep, so the blend still happens between the last two updates.
+#[derive(Debug)]
+pub struct Interpolated<S>
+where
+    S: Blend,
+{
+    /// The wrapped game state.
+    state: S,
+
+    /// The snapshot taken after the second-to-last update.
+    previous: S::Snapshot,
+
+    /// The snapshot taken after the last update.
+    current: S::Snapshot,
+}
+
+impl<S> Interpolated<S>
+where
+    S: Blend,
+{
+    /// Wrap the given game state.
+    ///
+    /// Until the state is updated twice, the missing snapshots are taken from
+    /// the initial state.
+    pub fn new(state: S) -> Self {
+        let current = state.snapshot();
+
+        Self {
+            state,
+            previous: current.clone(),
+            current,
+        }
+    }
+
+    /// The wrapped game state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// The wrapped game state, mutably.
+    ///
+    /// Changes made to the game state only show up in the snapshots after the
+    /// next update.
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Unwrap the game state.
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+}
+
+impl<S> Updater for Interpolated<S>
+where
+    S: Blend + Updater,
+{
+    type Error = <S as Updater>::Error;
+
+    fn update(&mut self) -> Result<(), Self::Error> {
+        self.state.update()?;
+        self.previous = mem::replace(&mut self.current, self.state.snapshot());
+
+        Ok(())
+    }
+
+    fn update_step(&mut self, step: Duration) -> Result<(), Self::Error> {
+        self.state.update_step(step)?;
+        self.previous = mem::replace(&mut self.current, self.state.snapshot());
+
+        Ok(())
+    }
+
+    fn update_substep(
+        &mut self,
+        step: Duration,
+        substep: u32,
+        substeps: u32,
+    ) -> Result<(), Self::Error> {
+        self.state.update_substep(step, substep, substeps)?;
+        if substep + 1 == substeps {
+            self.previous = mem::replace(&mut self.current, self.state.snapshot());
+        }
+
+        Ok(())
+    }
+
+    fn update_with<C>(&mut self, step: Duration, ctx: &C) -> Result<(), Self::Error>
+    where
+        C: Any,
+    {
+        self.state.update_with(step, ctx)?;
+        self.previous = mem::replace(&mut self.current, self.state.snapshot());
+
+        Ok(())
+    }
+
+    fn on_pause(&mut self) {
+        self.state.on_pause();
+    }
+
+    fn on_resume(&mut self) {
+        self.state.on_resume();
+    }
+
+    fn interpolation_corrected(&self) -> bool {
+        self.state.interpolation_corrected()
+    }
+
+    fn desired_update_rate(&self) -> Option<u32> {
+        self.state.desired_update_rate()
+    }
+
+    fn is_active(&self) -> bool {
+        self.state.is_active()
+    }
+}
+
+impl<S> Renderer for Interpolated<S>
+where
+    S: Blend,
+{
+    type Error = <S as Blend>::Error;
+
+    fn render(&mut self, remainder: Remainder) -> Result<(), Self::Error> {
+        let frame = S::blend(&self.previous, &self.current, remainder);
+
+        self.state.render_blended(frame)
+    }
+}
+
+/// A value that can be linearly interpolated between two of its instances.
+///
+/// This lets renderers interpolate structured data, such as positions, without
+/// hand-writing the same lerp over and over. See [`interpolate_between`].
+pub trait Interpolate {
+    /// Interpolate between `self` (at a remainder of `0.0`) and `other` (at a
+    /// remainder of `1.0`).
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self;
+}
+
+#[allow(
+    trivial_numeric_casts,
+    clippy::cast_possible_truncation,
+    clippy::cast_lossless
+)]
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self {
+        self + (other - self) * remainder as Self
+    }
+}
+
+#[allow(trivial_numeric_casts, clippy::cast_lossless)]
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self {
+        self + (other - self) * remainder as Self
+    }
+}
+
+impl<A, B> Interpolate for (A, B)
+where
+    A: Interpolate,
+    B: Interpolate,
+{
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self {
+        (
+            self.0.interpolate(&other.0, remainder),
+            self.1.interpolate(&other.1, remainder),
+        )
+    }
+}
+
+impl<A, B, C> Interpolate for (A, B, C)
+where
+    A: Interpolate,
+    B: Interpolate,
+    C: Interpolate,
+{
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self {
+        (
+            self.0.interpolate(&other.0, remainder),
+            self.1.interpolate(&other.1, remainder),
+            self.2.interpolate(&other.2, remainder),
+        )
+    }
+}
+
+/// Interpolate between the value of the previous update, and the value of the
+/// current update, based on the remainder passed to the [`Renderer`].
+///
+/// This is a convenience to use in [`Blend::blend`], or in a renderer that
+/// keeps the previous value around itself.
+pub fn interpolate_between<V>(previous: &V, current: &V, remainder: Remainder) -> V
+where
+    V: Interpolate,
+{
+    previous.interpolate(current, remainder)
+}
+
+/// Extrapolate a position from its velocity, to the moment in-between two
+/// updates represented by the given remainder.
+///
+/// This is a lighter-weight alternative to blending two snapshots with
+/// [`Interpolated`]: a game that knows the velocity of its entities only has to
+/// keep the current state around, and renders its entities at
+/// `position + velocity * remainder * step`. The velocity is expressed in units
+/// per second, and `step` is the update interval (see
+/// [`GameLoop::update_interval`]).
+///
+/// Unlike interpolation, extrapolation predicts where an entity is going to
+/// be, which visibly "stutters" when the next update contradicts that
+/// prediction, see [`Updater::interpolation_corrected`].
+///
+/// [`GameLoop::update_interval`]: crate::GameLoop::update_interval
+#[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+pub fn extrapolate(
+    position: Remainder,
+    velocity: Remainder,
+    remainder: Remainder,
+    step: Duration,
+) -> Remainder {
+    let step = step.as_nanos() as Remainder / NANOSECONDS_PER_SECOND as Remainder;
+
+    position + velocity * remainder * step
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::GameLoop;
+    use std::time::Duration;
+
+    #[derive(Debug, Default)]
+    struct State {
+        position: i32,
+        rendered: Vec<i32>,
+    }
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.position += 10;
+            Ok(())
+        }
+    }
+
+    impl Blend for State {
+        type Snapshot = i32;
+        type Frame = i32;
+        type Error = std::io::Error;
+
+        fn snapshot(&self) -> Self::Snapshot {
+            self.position
+        }
+
+        #[allow(
+            clippy::cast_lossless,
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss
+        )]
+        fn blend(previous: &i32, current: &i32, remainder: Remainder) -> i32 {
+            previous + ((current - previous) as Remainder * remainder) as i32
+        }
+
+        fn render_blended(&mut self, frame: i32) -> Result<(), Self::Error> {
+            self.rendered.push(frame);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_interpolated_renders_blended_snapshots() {
+        let mut game_loop = GameLoop::new(Interpolated::new(State::default()));
+
+        // One update moves the position from 0 to 10, and leaves half an
+        // update interval for the renderer to interpolate.
+        game_loop.add_accumulated_time(Duration::from_millis(15));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().state().rendered, vec![5]);
+    }
+
+    #[test]
+    fn test_interpolate_between_tuple() {
+        let (x, y) = interpolate_between(&(0.0_f32, 10.0_f32), &(4.0, 30.0), 0.25);
+
+        assert!((x - 1.0).abs() < std::f32::EPSILON);
+        assert!((y - 15.0).abs() < std::f32::EPSILON);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let position = extrapolate(10.0, 4.0, 0.5, Duration::from_millis(100));
+
+        assert!((position - 10.2).abs() < 1e-6);
+    }
+}
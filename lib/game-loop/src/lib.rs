@@ -37,10 +37,103 @@
     variant_size_differences,
     warnings
 )]
+// Types are re-exported at the crate root, so their module is never part of
+// their public path.
+#![allow(clippy::module_name_repetitions)]
 
-use std::fmt::Debug;
+mod builder;
+mod clock;
+mod config;
+mod event;
+mod headless;
+mod history;
+#[cfg(feature = "input")]
+mod input;
+mod interpolation;
+#[cfg(feature = "glam")]
+mod math;
+#[cfg(feature = "stats")]
+mod metrics;
+mod pipeline;
+#[cfg(feature = "replay")]
+mod replay;
+mod report;
+#[cfg(feature = "serde")]
+mod save;
+#[cfg(feature = "tracing")]
+mod spans;
+mod systems;
+#[cfg(feature = "metrics")]
+mod telemetry;
+#[cfg(feature = "testing")]
+mod testing;
+mod view_only;
+
+#[cfg(feature = "stats")]
+pub use crate::metrics::MetricsBatch;
+pub use builder::{BuilderWarning, GameLoopBuilder};
+#[cfg(feature = "wasm")]
+pub use clock::PerformanceClock;
+pub use clock::{Clock, ClockSource, FrameTimer, ManualClock, MonotonicClock};
+pub use config::{ConfigError, LoopConfig};
+#[cfg(feature = "winit")]
+pub use event::run_winit;
+pub use event::{Flow, LoopEvent};
+pub use headless::Headless;
+pub use history::History;
+#[cfg(feature = "input")]
+pub use input::{InputContext, InputSource};
+pub use interpolation::{extrapolate, interpolate_between, Blend, Interpolate, Interpolated};
+pub use pipeline::{LoopPipeline, PipelineError};
+#[cfg(feature = "replay")]
+pub use replay::ReplayDriver;
+pub use report::{TickReason, TickReport};
+pub use systems::Systems;
+#[cfg(feature = "testing")]
+pub use testing::RecordingRenderer;
+pub use view_only::ViewOnly;
+
+#[cfg(feature = "stats")]
+use crate::metrics::Metrics;
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+/// The type of the remainder passed to the [`Renderer`].
+///
+/// Defaults to `f32`, enable the `high-precision` feature to use `f64` instead.
+#[cfg(not(feature = "high-precision"))]
+pub type Remainder = f32;
+
+/// The type of the remainder passed to the [`Renderer`].
+///
+/// Defaults to `f32`, enable the `high-precision` feature to use `f64` instead.
+#[cfg(feature = "high-precision")]
+pub type Remainder = f64;
+
+/// The context passed along with ticks that don't have one, see
+/// [`GameLoop::tick_with_context`].
+const NO_CONTEXT: Option<&()> = None;
+
+/// The maximum amount of extra updates the renderer can request during a
+/// single tick, see [`RenderOutcome::request_extra_update`].
+const MAX_EXTRA_UPDATES: usize = 3;
+
+/// The maximum amount of updates a single call to [`GameLoop::catch_up_to`]
+/// runs.
+const MAX_CATCH_UP_TO_UPDATES: usize = 1024;
+
+/// The amount of consecutive ticks that have to agree, before the game loop
+/// enters or leaves the catch-up regime, see [`GameLoop::set_on_catch_up_start`].
+const CATCH_UP_TICKS: usize = 2;
+
 /// Convenience constant, to make the rest of the code a bit easier to parse.
 const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
 
@@ -52,7 +145,7 @@ const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
 /// This is an internal representation, because the state can never be anything
 /// other than `Idle` before and after running `tick()`.
 ///
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum State {
     /// The `Idle` state represents the state the [`GameLoop`] is in right
     /// before calling [`tick()`], and after that method is completed.
@@ -70,6 +163,128 @@ enum State {
     Rendering,
 }
 
+/// The _public_ mirror of the internal state of the [`GameLoop`].
+///
+/// This allows tooling (such as a visual debugger) to observe the phase the
+/// loop is in, without exposing the internal state machine itself.
+///
+/// Because ticks run synchronously, the phase observed from outside of
+/// [`GameLoop::tick`] is always `Idle`, unless the last tick failed, in which
+/// case it reflects the phase in which the error occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// The loop is not running a tick.
+    Idle,
+
+    /// The loop is updating the game state.
+    Updating,
+
+    /// The loop is rendering the game state.
+    Rendering,
+}
+
+impl From<State> for Phase {
+    fn from(state: State) -> Self {
+        match state {
+            State::Idle => Phase::Idle,
+            State::Updating => Phase::Updating,
+            State::Rendering => Phase::Rendering,
+        }
+    }
+}
+
+/// How the game loop steps the simulation forward, see
+/// [`GameLoop::set_timestep_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimestepMode {
+    /// Every update simulates exactly one update interval, running as many
+    /// updates as the accumulated time allows.
+    Fixed,
+
+    /// Every tick runs a single update, simulating the time passed since the
+    /// previous tick, without accumulating any time.
+    Variable,
+}
+
+impl Default for TimestepMode {
+    fn default() -> Self {
+        TimestepMode::Fixed
+    }
+}
+
+/// How the renderer positions the game state relative to the last update, see
+/// [`GameLoop::set_render_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    /// Render in-between the last two updates, slightly in the past.
+    Interpolate,
+
+    /// Render ahead of the last update, predicting the next update.
+    Extrapolate,
+}
+
+impl Default for RenderMode {
+    fn default() -> Self {
+        RenderMode::Interpolate
+    }
+}
+
+/// What the game loop does when its clock runs backwards.
+///
+/// See [`GameLoopBuilder::backward_time_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackwardTimePolicy {
+    /// Ignore the tick that measured the clock running backwards, no time
+    /// accumulates during that tick.
+    Ignore,
+
+    /// Fail the tick that measured the clock running backwards, with
+    /// [`Error::BackwardTime`].
+    Error,
+}
+
+impl Default for BackwardTimePolicy {
+    fn default() -> Self {
+        BackwardTimePolicy::Ignore
+    }
+}
+
+/// The phase of the tick holding back the game loop, see
+/// [`GameLoop::bottleneck`].
+#[cfg(feature = "stats")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bottleneck {
+    /// Updating and rendering fit within the frame budget.
+    WithinBudget,
+
+    /// The frame budget is exceeded, mostly by updating the game state.
+    Update,
+
+    /// The frame budget is exceeded, mostly by rendering the game state.
+    Render,
+}
+
+/// What [`GameLoop::run`] does with the accumulated time left when it stops.
+///
+/// See [`GameLoopBuilder::shutdown_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownPolicy {
+    /// Stop right away, leaving the accumulated time undrained.
+    Immediate,
+
+    /// Update the game state until the accumulated time is drained (respecting
+    /// the maximum updates per tick), and render it once more, so that the
+    /// simulation ends on an update boundary.
+    DrainUpdates,
+}
+
+impl Default for ShutdownPolicy {
+    fn default() -> Self {
+        ShutdownPolicy::Immediate
+    }
+}
+
 /// The trait responsible for _updating_ the state of the game world.
 ///
 /// It requires a single method [`update()`] to be implemented.
@@ -88,6 +303,120 @@ pub trait Updater: Debug {
     /// If this method returns an error, the game loop will bubble up that error
     /// to the callee of [`GameLoop::tick`].
     fn update(&mut self) -> Result<(), Self::Error>;
+
+    /// Update the game state by a fixed step of simulated time.
+    ///
+    /// The game loop calls this method for every update, with the update
+    /// interval as the step. Updates subdivided into substeps call
+    /// [`Updater::update_substep`] instead.
+    ///
+    /// By default, the step is ignored, and [`Updater::update`] is called.
+    fn update_step(&mut self, step: Duration) -> Result<(), Self::Error> {
+        let _ = step;
+        self.update()
+    }
+
+    /// Update the game state by a single substep of an update, see
+    /// [`GameLoop::set_update_substeps`].
+    ///
+    /// With more than one substep, the game loop calls this method instead of
+    /// [`Updater::update_step`], `substeps` times for every update, with an
+    /// equal fraction of the update interval as the step, and the index of the
+    /// substep, starting at zero.
+    ///
+    /// By default, the substeps are ignored: only the last substep updates
+    /// the game state, calling [`Updater::update_step`] with the step of the
+    /// whole update. A game state that doesn't implement this method advances
+    /// just as fast as without substeps.
+    fn update_substep(
+        &mut self,
+        step: Duration,
+        substep: u32,
+        substeps: u32,
+    ) -> Result<(), Self::Error> {
+        if substep + 1 < substeps {
+            return Ok(());
+        }
+
+        self.update_step(step * substeps)
+    }
+
+    /// The update rate (in updates per second) the game state would like the
+    /// game loop to run at, if it differs from the configured rate.
+    ///
+    /// The game loop polls this method at the start of every tick, so a rate
+    /// change requested while the game loop is draining its accumulated time
+    /// only takes effect on the next tick. The time still accumulated at that
+    /// point is drained in steps of the new update interval.
+    ///
+    /// Rates the game loop can't run at, zero or more than a billion updates
+    /// per second, are ignored.
+    ///
+    /// Returns `None` by default, keeping the configured rate.
+    fn desired_update_rate(&self) -> Option<u32> {
+        None
+    }
+
+    /// Update the game state, with the context passed to
+    /// [`GameLoop::tick_with_context`].
+    ///
+    /// The context carries transient, tick-scoped data from the host, such as
+    /// a snapshot of the input, that doesn't belong in the game state. As the
+    /// context can be of any type, it has to be downcast to the expected type,
+    /// through [`Any`].
+    ///
+    /// This method is called instead of [`Updater::update_step`], for every
+    /// update of a tick with a context, with the same step. Updates with a
+    /// context aren't divided into substeps (see
+    /// [`GameLoop::set_update_substeps`]): this method is called once per
+    /// update, with the step of the whole update, and is free to subdivide
+    /// the step itself. By default, the context is ignored, and
+    /// [`Updater::update_step`] is called.
+    fn update_with<C>(&mut self, step: Duration, ctx: &C) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+        C: Any,
+    {
+        let _ = ctx;
+        self.update_step(step)
+    }
+
+    /// Called when the game loop is paused, see [`GameLoop::pause`].
+    ///
+    /// This allows the game to respond to the pause, for example by muting
+    /// the audio, or showing a pause overlay. Does nothing by default.
+    fn on_pause(&mut self) {}
+
+    /// Called when the game loop is resumed, see [`GameLoop::resume`].
+    ///
+    /// Does nothing by default.
+    fn on_resume(&mut self) {}
+
+    /// Whether the last update corrected a misprediction of the interpolation.
+    ///
+    /// When the renderer extrapolates the game state beyond the last update
+    /// (for example, moving a character forward), and the next update
+    /// contradicts that prediction (the character stopped), the rendered
+    /// frames visibly "stutter" back. With the `stats` feature enabled, the
+    /// game loop polls this method after every update, and counts the
+    /// corrections, see `GameLoop::stutter_count`.
+    ///
+    /// Returns `false` by default.
+    fn interpolation_corrected(&self) -> bool {
+        false
+    }
+
+    /// Whether the game is active, for example because the player provided
+    /// input recently.
+    ///
+    /// The game loop polls this method after every update, and at the start
+    /// of every tick while automatically paused, see
+    /// [`GameLoop::set_auto_pause_after`].
+    ///
+    /// Returns `true` by default.
+    fn is_active(&self) -> bool {
+        true
+    }
 }
 
 /// The trait responsible for _rendering_ the state of the game world.
@@ -111,7 +440,92 @@ pub trait Renderer: Debug {
     ///
     /// If this method returns an error, the game loop will bubble up that error
     /// to the callee of [`GameLoop::tick`].
-    fn render(&mut self, remainder: f32) -> Result<(), Self::Error>;
+    fn render(&mut self, remainder: Remainder) -> Result<(), Self::Error>;
+
+    /// Render the game state, and report the outcome of the render.
+    ///
+    /// This allows the renderer to veto presenting the rendered frame, for
+    /// example to skip swapping buffers when nothing visibly changed. The
+    /// outcome is surfaced in the report of the tick, see
+    /// [`TickReport::presented`].
+    ///
+    /// The renderer can also request one more update, see
+    /// [`RenderOutcome::request_extra_update`].
+    ///
+    /// By default, [`Renderer::render`] is called, and the frame is presented.
+    fn render_outcome(&mut self, remainder: Remainder) -> Result<RenderOutcome, Self::Error> {
+        self.render(remainder)?;
+
+        Ok(RenderOutcome::default())
+    }
+
+    /// Render the game state, with the context passed to
+    /// [`GameLoop::tick_with_context`].
+    ///
+    /// See [`Updater::update_with`] for more details on the context. By
+    /// default, the context is ignored, and [`Renderer::render`] is called.
+    fn render_with<C>(&mut self, remainder: Remainder, ctx: &C) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+        C: Any,
+    {
+        let _ = ctx;
+        self.render(remainder)
+    }
+
+    /// Render the game state, with the context passed to
+    /// [`GameLoop::tick_with_context`], and report the outcome of the render.
+    ///
+    /// This is the counterpart of [`Renderer::render_outcome`] for ticks with a
+    /// context, which call this method instead. By default,
+    /// [`Renderer::render_with`] is called, and the frame is presented.
+    fn render_outcome_with<C>(
+        &mut self,
+        remainder: Remainder,
+        ctx: &C,
+    ) -> Result<RenderOutcome, Self::Error>
+    where
+        Self: Sized,
+        C: Any,
+    {
+        self.render_with(remainder, ctx)?;
+
+        Ok(RenderOutcome::default())
+    }
+}
+
+/// The outcome of rendering the game state, see [`Renderer::render_outcome`].
+///
+/// For ticks with a context, see [`Renderer::render_outcome_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOutcome {
+    /// Whether the rendered frame should be presented, for example by swapping
+    /// buffers.
+    pub presented: bool,
+
+    /// Whether the game state turned out to be stale, and should be updated
+    /// once more, before rendering it again.
+    ///
+    /// This is an escape hatch for rare cases, such as an asset that just
+    /// finished loading. The extra update doesn't drain any accumulated time.
+    /// To prevent an endless loop, a tick runs at most 3 extra updates, after
+    /// which further requests are ignored.
+    ///
+    /// Otherwise, extra updates are held to the same rules as regular updates:
+    /// they invoke the update boundary hook, count towards the update ceiling,
+    /// and are ignored while the game loop is paused, or has reached its update
+    /// ceiling. See [`GameLoop::set_on_update_boundary`] and
+    /// [`GameLoop::set_max_updates_per_real_second`].
+    pub request_extra_update: bool,
+}
+
+impl Default for RenderOutcome {
+    fn default() -> Self {
+        Self {
+            presented: true,
+            request_extra_update: false,
+        }
+    }
 }
 
 /// The main game loop.
@@ -120,12 +534,15 @@ pub trait Renderer: Debug {
 /// methods when needed.
 ///
 /// The loop does not advance by itself, you are still required to call `tick`
-/// to execute the next game tick.
+/// to execute the next game tick, or `run` to keep ticking until a stop is
+/// requested through the loop's [`StopHandle`].
 ///
 /// You can choose to update the game as fast as possible by calling `tick` in
 /// an infinite loop, limit the max frames by sleeping between ticks, or
 /// manually advance the game state by calling `tick` whenever you need to, for
 /// example when running tests.
+///
+/// Use [`GameLoopBuilder`] to configure the loop before it is created.
 #[derive(Debug)]
 pub struct GameLoop<T>
 where
@@ -208,19 +625,373 @@ where
     /// for performance reasons, but not until we measure the results. For now
     /// this is fine.
     accumulated_time: Duration,
+
+    /// Whether [`GameLoop::run`] renders the game state one last time before
+    /// returning, if the state changed since the last render.
+    render_on_exit: bool,
+
+    /// What [`GameLoop::run`] does with the accumulated time left when it
+    /// stops.
+    shutdown_policy: ShutdownPolicy,
+
+    /// What the game loop does when its clock runs backwards.
+    backward_time_policy: BackwardTimePolicy,
+
+    /// Set when the game state was updated, and unset when it is rendered.
+    ///
+    /// This tells us if the latest game state has made it to the screen.
+    render_pending: bool,
+
+    /// The handle used to request the loop to stop.
+    stop_handle: StopHandle,
+
+    /// The phase of the tick currently running, see [`Phase`].
+    phase: Phase,
+
+    /// The clock used to measure the time between ticks.
+    clock: Arc<dyn Clock>,
+
+    /// The clock owned by the game loop, if configured with the
+    /// [`ClockSource::Manual`] clock source.
+    ///
+    /// This is the same clock as `clock`, kept around to advance it.
+    manual_clock: Option<ManualClock>,
+
+    /// The clock used to pace the renders, if it differs from `clock`.
+    ///
+    /// See [`GameLoopBuilder::render_clock`].
+    render_clock: Option<Arc<dyn Clock>>,
+
+    /// The time of the render clock at the previous tick, if read before.
+    render_clock_read_at: Option<Duration>,
+
+    /// The upper bound of the remainder passed to the renderer.
+    ///
+    /// See [`GameLoop::set_max_interpolation`].
+    max_interpolation: Remainder,
+
+    /// Whether the loop advances by exactly one update interval per tick,
+    /// without ever reading the clock.
+    ///
+    /// See [`GameLoopBuilder::deterministic`].
+    deterministic: bool,
+
+    /// Whether the game state updates are paused.
+    ///
+    /// See [`GameLoop::pause`].
+    paused: bool,
+
+    /// Whether the game loop is suspended, see [`GameLoop::suspend`].
+    suspended: bool,
+
+    /// Whether the next tick ignores the time passed since the previous tick,
+    /// because the game loop resumed from a suspension in-between.
+    skip_next_frame_time: bool,
+
+    /// Whether ticks render the game state.
+    ///
+    /// See [`GameLoop::set_render_enabled`].
+    render_enabled: bool,
+
+    /// Render only every this many ticks.
+    ///
+    /// See [`GameLoop::set_render_every`].
+    render_every: usize,
+
+    /// The amount of ticks completed since the game state was last rendered.
+    ticks_since_render: usize,
+
+    /// The shortest time between two renders, if any.
+    ///
+    /// See [`GameLoop::set_max_render_fps`].
+    min_render_interval: Option<Duration>,
+
+    /// The time accumulated towards the next render, if the time between two
+    /// renders is limited.
+    render_time: Duration,
+
+    /// How long after a resize render errors are swallowed.
+    ///
+    /// See [`GameLoop::set_suppress_render_errors`].
+    suppress_render_errors: Duration,
+
+    /// The time of the clock at which the window was last resized, if ever.
+    resized_at: Option<Duration>,
+
+    /// The amount of updates run since the game loop was created.
+    ///
+    /// See [`GameLoop::update_sequence`].
+    update_sequence: u64,
+
+    /// The time simulated by the updates run since the game loop was created.
+    ///
+    /// See [`GameLoop::simulation_time`].
+    simulation_time: Duration,
+
+    /// The simulated time the game has to be inactive, before the game loop
+    /// pauses automatically.
+    ///
+    /// See [`GameLoop::set_auto_pause_after`].
+    auto_pause_after: Option<Duration>,
+
+    /// The simulated time the game reported itself inactive for.
+    idle_time: Duration,
+
+    /// Whether the game loop paused automatically, because the game was
+    /// inactive.
+    auto_paused: bool,
+
+    /// The label of the game loop, if any.
+    ///
+    /// See [`GameLoop::set_label`].
+    label: Option<Arc<str>>,
+
+    /// The fraction of time the game loop may keep the CPU busy.
+    ///
+    /// See [`GameLoop::set_cpu_budget`].
+    cpu_budget: Option<f32>,
+
+    /// The shortest time measured between two ticks.
+    ///
+    /// See [`GameLoop::set_min_frame_time`].
+    min_frame_time: Duration,
+
+    /// The longest time a single tick accumulates, if any.
+    ///
+    /// See [`GameLoop::set_max_frame_time`].
+    max_frame_time: Option<Duration>,
+
+    /// How the game loop steps the simulation forward.
+    ///
+    /// See [`GameLoop::set_timestep_mode`].
+    timestep_mode: TimestepMode,
+
+    /// How the renderer positions the game state relative to the last update.
+    ///
+    /// See [`GameLoop::set_render_mode`].
+    render_mode: RenderMode,
+
+    /// The frame time the next tick simulates, instead of measuring it.
+    ///
+    /// See [`GameLoop::advance_frame`].
+    forced_frame_time: Option<Duration>,
+
+    /// The timestamp supplied to the previous call to [`GameLoop::tick_at`].
+    tick_at_reference: Option<Instant>,
+
+    /// The callback invoked right before every update.
+    ///
+    /// See [`GameLoop::set_on_update_boundary`].
+    on_update_boundary: Option<UpdateBoundaryHook>,
+
+    /// The callback invoked when the game loop starts catching up.
+    ///
+    /// See [`GameLoop::set_on_catch_up_start`].
+    on_catch_up_start: Option<CatchUpHook>,
+
+    /// The callback invoked when the game loop stops catching up.
+    ///
+    /// See [`GameLoop::set_on_catch_up_end`].
+    on_catch_up_end: Option<CatchUpHook>,
+
+    /// Whether the game loop is catching up, running more than one update
+    /// per tick.
+    catching_up: bool,
+
+    /// The amount of consecutive ticks that disagreed with `catching_up`.
+    catch_up_streak: usize,
+
+    /// The callback invoked once every real second.
+    ///
+    /// See [`GameLoop::set_on_second`].
+    on_second: Option<SecondHook>,
+
+    /// The clock reading of the start of the tick that last invoked the
+    /// callback set through [`GameLoop::set_on_second`], or the first tick
+    /// since it was set.
+    second_started_at: Option<Duration>,
+
+    /// The longest a render may take, and the callback invoked when it takes
+    /// longer.
+    ///
+    /// See [`GameLoop::set_render_budget`].
+    render_budget: Option<RenderBudget>,
+
+    /// The writer every tick report is traced to.
+    ///
+    /// See [`GameLoop::set_trace_writer`].
+    trace_writer: Option<TraceWriter>,
+
+    /// The clock reading at the start of the very first tick.
+    ///
+    /// See [`GameLoop::real_elapsed`].
+    first_tick_at: Option<Duration>,
+
+    /// The amount of substeps every update is divided into.
+    ///
+    /// See [`GameLoop::set_update_substeps`].
+    update_substeps: u32,
+
+    /// The metrics collected while running the game loop.
+    #[cfg(feature = "stats")]
+    metrics: Metrics,
+
+    /// The maximum amount of updates per tick.
+    ///
+    /// See [`GameLoop::set_max_updates_per_tick`].
+    max_updates_per_tick: Option<usize>,
+
+    /// The maximum amount of updates per second of (clock) time.
+    ///
+    /// See [`GameLoop::set_max_updates_per_real_second`].
+    max_updates_per_real_second: Option<u32>,
+
+    /// The maximum accumulated time, as a multiple of the update interval.
+    ///
+    /// See [`GameLoop::set_max_accumulated_intervals`].
+    max_accumulated_intervals: Option<u32>,
+
+    /// The start of the ticks that ran the updates during the last second of
+    /// (clock) time, oldest first, one entry per update.
+    ///
+    /// Only kept while the updates per second of (clock) time are limited.
+    recent_updates: VecDeque<Duration>,
+
+    /// The temporarily boosted update rate, if any.
+    ///
+    /// See [`GameLoop::boost_update_rate`].
+    boost: Option<Boost>,
+}
+
+/// A handle to request a [`GameLoop`] to stop running.
+///
+/// The handle can be cloned and moved into the game state (or another thread),
+/// allowing the game to stop the loop from the inside.
+///
+/// Once a stop is requested, the loop won't run any more updates, including any
+/// updates still pending in the current tick.
+#[derive(Debug, Clone, Default)]
+pub struct StopHandle(Arc<AtomicBool>);
+
+impl StopHandle {
+    /// Request the game loop to stop.
+    pub fn stop(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether a stop was requested.
+    pub fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
 }
 
 /// Represents a single "tick" of the game loop.
 #[derive(Debug)]
 struct Tick {
     /// Whenever a new "tick" is started, this field is set to the current
-    /// timestamp. An [`Instant`] is used to record the time, so it can only be
-    /// used to measure the duration between two ticks, not to record _when_ a
-    /// tick was started.
-    started_at: Instant,
+    /// reading of the game loop's [`Clock`]. A clock reading is only relative
+    /// to the (arbitrary) origin of the clock, so it can only be used to
+    /// measure the duration between two ticks, not to record _when_ a tick was
+    /// started.
+    started_at: Duration,
 
     /// The state that the tick is currently in.
     state: State,
+
+    /// The amount of times the game state was updated during this tick.
+    updates: usize,
+
+    /// Whether the game state was rendered during this tick.
+    rendered: bool,
+
+    /// Whether the frame rendered during this tick should be presented.
+    presented: bool,
+
+    /// The time measured since the start of the previous tick, if any.
+    frame_time: Option<Duration>,
+
+    /// The step of the single update still to run in this tick, in the
+    /// variable timestep mode.
+    variable_step: Option<Duration>,
+
+    /// The amount of render errors swallowed during this tick.
+    suppressed_render_errors: usize,
+}
+
+/// The callback invoked right before every update, see
+/// [`GameLoop::set_on_update_boundary`].
+struct UpdateBoundaryHook(Box<dyn FnMut(u64) + Send>);
+
+impl Debug for UpdateBoundaryHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("UpdateBoundaryHook")
+    }
+}
+
+/// The callback invoked when the game loop enters or leaves the catch-up
+/// regime, see [`GameLoop::set_on_catch_up_start`].
+struct CatchUpHook(Box<dyn FnMut() + Send>);
+
+impl Debug for CatchUpHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CatchUpHook")
+    }
+}
+
+/// The callback invoked once every real second, see
+/// [`GameLoop::set_on_second`].
+struct SecondHook(Box<dyn FnMut(&TickReport) + Send>);
+
+impl Debug for SecondHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecondHook")
+    }
+}
+
+/// The longest a render may take, see [`GameLoop::set_render_budget`].
+struct RenderBudget {
+    /// The longest a render may take.
+    budget: Duration,
+
+    /// The callback invoked with the time a render took, if it exceeded the
+    /// budget.
+    on_exceeded: Box<dyn FnMut(Duration) + Send>,
+}
+
+impl Debug for RenderBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenderBudget")
+            .field("budget", &self.budget)
+            .finish()
+    }
+}
+
+/// The observers of a single tick, see [`GameLoop::tick_observed`].
+struct TickObservers<'a> {
+    /// Called right after every update, with its sequence number.
+    on_update: &'a mut dyn FnMut(u64),
+
+    /// Called right after every render, with its remainder.
+    on_render: &'a mut dyn FnMut(Remainder),
+}
+
+/// The writer tick reports are traced to, see [`GameLoop::set_trace_writer`].
+struct TraceWriter(Box<dyn Write + Send>);
+
+impl Debug for TraceWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TraceWriter")
+    }
+}
+
+/// A temporary boost of the update rate of the game loop.
+#[derive(Debug, Clone, Copy)]
+struct Boost {
+    /// The amount of ticks left before the boost ends.
+    remaining_ticks: usize,
+
+    /// The update interval to restore once the boost ends.
+    restore_interval: Duration,
 }
 
 /// The error state of the game loop.
@@ -238,250 +1009,4186 @@ where
 
     /// The render call produced an error.
     Render(<T as Renderer>::Error),
-}
 
-impl Default for Tick {
-    fn default() -> Self {
-        Self {
-            started_at: Instant::now(),
-            state: State::Idle,
-        }
-    }
+    /// The clock ran backwards by the given duration, see
+    /// [`BackwardTimePolicy::Error`].
+    BackwardTime(Duration),
 }
 
-impl<T> GameLoop<T>
+impl<T> Error<T>
 where
     T: Updater + Renderer,
+    <T as Updater>::Error: Send + Sync + 'static,
+    <T as Renderer>::Error: Send + Sync + 'static,
 {
-    /// Create a new game loop with the given state.
-    pub fn new(state: T) -> Self {
-        // Sets the game state update to a fixed interval. This is what
-        // decouples your game update behaviour from the speed at which the game
-        // is rendered to the screen (FPS).
-        //
-        // # See Also
-        //
-        // * https://www.koonsolo.com/news/dewitters-gameloop/
-        // * https://gafferongames.com/post/fix_your_timestep/
-        // * http://gameprogrammingpatterns.com/game-loop.html
-        //
-        // TODO: move this into a configuration struct, or add a builder.
-        let updates_per_second = 100;
-
-        Self {
-            state,
-            previous_tick: None,
-            accumulated_time: Duration::default(),
-            update_interval: Duration::from_nanos(
-                u64::from(NANOSECONDS_PER_SECOND) / updates_per_second,
-            ),
+    /// Unwrap the update or render error into a single boxed error.
+    ///
+    /// This unifies mismatched update and render error types, for ergonomic
+    /// use of `?`, for example in `main`.
+    pub fn into_boxed(self) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        match self {
+            Error::Update(err) => Box::new(err),
+            Error::Render(err) => Box::new(err),
+            Error::BackwardTime(went_back) => {
+                format!("the clock ran backwards by {:?}", went_back).into()
+            }
         }
     }
+}
 
-    /// A tick is a single "step" forward for the entire state of the game.
+/// An invariant of the game loop that doesn't hold.
+///
+/// See [`GameLoop::validate_invariants`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// The remainder is not lower than 1.0, meaning at least one more update
+    /// is due before rendering.
+    RemainderOutOfRange,
+
+    /// The update interval is zero, which happens if the game loop is
+    /// configured with more than a billion updates per second.
+    ZeroUpdateInterval,
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantViolation::RemainderOutOfRange => {
+                f.write_str("the remainder must be lower than 1.0")
+            }
+            InvariantViolation::ZeroUpdateInterval => {
+                f.write_str("the update interval must not be zero")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+impl Tick {
+    /// Create a new tick, started at the given clock reading.
+    const fn new(started_at: Duration) -> Self {
+        Self {
+            started_at,
+            state: State::Idle,
+            updates: 0,
+            rendered: false,
+            presented: false,
+            frame_time: None,
+            variable_step: None,
+            suppressed_render_errors: 0,
+        }
+    }
+}
+
+// Methods taking another game loop, such as `with_shared_clock`, name it in
+// full, as it owns a different game state, so it isn't `Self`.
+#[allow(clippy::use_self)]
+impl<T> GameLoop<T>
+where
+    T: Updater + Renderer,
+{
+    /// Create a new game loop with the given state, using the default
+    /// configuration of [`GameLoopBuilder`].
+    pub fn new(state: T) -> Self {
+        GameLoopBuilder::default()
+            .build(state)
+            .expect("the default configuration is valid")
+    }
+
+    /// Create a new deterministic game loop with the given state.
+    ///
+    /// Every tick of a deterministic loop runs exactly one update, and one
+    /// render, without ever reading the clock. See
+    /// [`GameLoopBuilder::deterministic`] for more details.
+    pub fn deterministic(state: T) -> Self {
+        GameLoopBuilder::default()
+            .deterministic(true)
+            .build(state)
+            .expect("the default configuration is valid")
+    }
+
+    /// Create a new game loop with the given state, and configuration.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the configuration is invalid, see [`LoopConfig::validate`].
+    pub fn with_config(state: T, config: &LoopConfig) -> Result<Self, ConfigError> {
+        config.validate()?;
+
+        let mut game_loop = config
+            .builder()
+            .build(state)
+            .expect("the configuration was validated");
+        game_loop.set_max_updates_per_tick(config.max_updates_per_tick);
+        game_loop.set_update_substeps(config.update_substeps);
+        game_loop.set_max_interpolation(config.max_interpolation);
+        game_loop.set_render_every(config.render_every);
+
+        Ok(game_loop)
+    }
+
+    /// Create a new game loop with the given state, reading the same clock as
+    /// the given primary game loop.
+    ///
+    /// Loops that each read their own clock slowly drift apart, loops that
+    /// share a clock stay in lockstep, for example a simulation loop and an
+    /// audio loop. If the primary loop uses the [`ClockSource::Manual`] clock
+    /// source, advancing either loop advances both. Apart from the clock, the
+    /// loop uses the default configuration of [`GameLoopBuilder`].
+    pub fn with_shared_clock<U>(state: T, primary: &GameLoop<U>) -> Self
+    where
+        U: Updater + Renderer,
+    {
+        let mut game_loop = Self::new(state);
+        game_loop.clock = Arc::clone(&primary.clock);
+        game_loop.manual_clock = primary.manual_clock.clone();
+
+        game_loop
+    }
+
+    /// Keep ticking the game loop, until a stop is requested.
+    ///
+    /// Once stopped, the accumulated time is handled according to the
+    /// configured [`ShutdownPolicy`].
+    ///
+    /// If the loop is configured to render on exit, the renderer is called one
+    /// last time before returning, if the game state changed since the last
+    /// render. Because the accumulated time was not necessarily drained at
+    /// that point, the renderer receives a remainder of `0.0`, representing
+    /// the game state exactly as the last update left it.
+    pub fn run(&mut self) -> Result<(), Error<T>> {
+        while !self.stop_handle.is_stopped() {
+            let started_at = self.clock.now();
+            self.tick()?;
+            self.throttle(started_at);
+        }
+
+        self.finish()
+    }
+
+    /// Keep ticking the game loop, until a stop is requested, paced by the
+    /// given frame timer.
+    ///
+    /// Before every tick, the game loop waits for the frame timer, and ticks
+    /// with the time it reports as passed since the previous frame (see
+    /// [`GameLoop::advance_frame`]), instead of measuring the time itself.
+    /// The CPU budget (see [`GameLoop::set_cpu_budget`]) doesn't apply, the
+    /// frame timer paces the game loop.
+    ///
+    /// Once stopped, the game loop wraps up just like [`GameLoop::run`].
+    pub fn run_with_timer<F>(&mut self, timer: &mut F) -> Result<(), Error<T>>
+    where
+        F: FrameTimer,
+    {
+        while !self.stop_handle.is_stopped() {
+            let frame_time = timer.wait_for_next_frame();
+            self.advance_frame(frame_time)?;
+        }
+
+        self.finish()
+    }
+
+    /// Keep ticking the game loop, until the given predicate over the game
+    /// state holds.
+    ///
+    /// The predicate is checked after every tick. This suits scripted tests,
+    /// such as "run until the player reaches the door". To guard against
+    /// predicates that never hold, the loop gives up after `max_ticks` ticks,
+    /// if given, or once a stop is requested.
+    ///
+    /// Returns whether the predicate holds.
+    pub fn run_until<P>(&mut self, max_ticks: Option<usize>, predicate: P) -> Result<bool, Error<T>>
+    where
+        P: Fn(&T) -> bool,
+    {
+        let mut ticks = 0;
+        while max_ticks.map_or(true, |max_ticks| ticks < max_ticks) {
+            if self.stop_handle.is_stopped() {
+                break;
+            }
+
+            self.tick()?;
+            ticks += 1;
+
+            if predicate(&self.state) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Handle an event of a windowing event loop.
+    ///
+    /// This drives the game loop from an event loop (such as the one provided
+    /// by `winit`), instead of calling `tick` in a loop:
+    ///
+    /// * [`LoopEvent::EventsCleared`] ticks the game loop.
+    /// * [`LoopEvent::RedrawRequested`] renders the game state right away.
+    /// * [`LoopEvent::CloseRequested`] stops the game loop, the same way
+    ///   [`GameLoop::run`] stops, including the optional render on exit.
+    /// * [`LoopEvent::Resized`] notifies the game loop of the resize, see
+    ///   [`GameLoop::notify_resized`].
+    ///
+    /// The returned [`Flow`] tells the event loop whether to exit, which also
+    /// happens once a stop is requested through the loop's [`StopHandle`].
+    pub fn handle_event(&mut self, event: LoopEvent) -> Result<Flow, Error<T>> {
+        match event {
+            LoopEvent::EventsCleared => self.tick()?,
+            LoopEvent::RedrawRequested => self.render_now()?,
+            LoopEvent::CloseRequested => self.stop_handle.stop(),
+            LoopEvent::Resized => self.notify_resized(),
+            LoopEvent::Ignored => {}
+        }
+
+        if self.stop_handle.is_stopped() {
+            self.finish()?;
+            return Ok(Flow::Exit);
+        }
+
+        Ok(Flow::Continue)
+    }
+
+    /// Render the game state right away, outside of a regular tick.
+    ///
+    /// The renderer receives the current remainder, clamped to the configured
+    /// maximum interpolation.
+    pub fn render_now(&mut self) -> Result<(), Error<T>> {
+        let remainder = self.current_render_remainder();
+
+        self.run_render(remainder, NO_CONTEXT).map(|_| ())
+    }
+
+    /// Render the game state right away, if it was updated since it was last
+    /// rendered, or do nothing otherwise.
+    ///
+    /// When ticks skip rendering, such as while rendering only every few ticks
+    /// (see [`GameLoop::set_render_every`]), the last updates might never make
+    /// it to the screen. Flushing guarantees the pending render happens, for
+    /// example before shutting down. Like [`GameLoop::render_now`], the
+    /// renderer receives the current remainder, clamped to the configured
+    /// maximum interpolation. See also [`GameLoopBuilder::render_on_exit`].
+    pub fn flush(&mut self) -> Result<(), Error<T>> {
+        if self.render_pending {
+            self.render_now()?;
+        }
+
+        Ok(())
+    }
+
+    /// The real (wall-clock) time passed since the start of the first tick.
+    ///
+    /// This is separate from the time simulated by the game loop, for example
+    /// to show the duration of the play session. Returns zero if the loop never
+    /// ticked, or if the loop is deterministic, as it never reads the clock.
+    pub fn real_elapsed(&self) -> Duration {
+        self.first_tick_at
+            .and_then(|first_tick_at| self.clock.now().checked_sub(first_tick_at))
+            .unwrap_or_default()
+    }
+
+    /// Consume the game loop, returning the game state.
+    pub fn into_inner(self) -> T {
+        self.state
+    }
+
+    /// Set a callback to invoke the instant the accumulated time crosses an
+    /// update boundary, right before the game state is updated.
+    ///
+    /// The callback receives the index of the update within the current tick,
+    /// so while catching up with two updates, it is invoked with `0`, and then
+    /// with `1`. Unlike hooks that run once per tick, this fires for every
+    /// update, for example to keep audio in sync with the simulation.
+    ///
+    /// The callback isn't carried over when cloning the game loop.
+    pub fn set_on_update_boundary(&mut self, hook: Box<dyn FnMut(u64) + Send>) {
+        self.on_update_boundary = Some(UpdateBoundaryHook(hook));
+    }
+
+    /// Set a callback to invoke when the game loop starts catching up.
+    ///
+    /// The game loop is catching up once 2 consecutive ticks ran more than one
+    /// update each, and stops catching up once 2 consecutive ticks ran at
+    /// most one update each, see [`GameLoop::set_on_catch_up_end`]. The
+    /// callbacks are edge-triggered, for example to show a "performance
+    /// degraded" notice, without having to poll the tick reports.
+    ///
+    /// The callback isn't carried over when cloning the game loop.
+    pub fn set_on_catch_up_start(&mut self, hook: Box<dyn FnMut() + Send>) {
+        self.on_catch_up_start = Some(CatchUpHook(hook));
+    }
+
+    /// Set a callback to invoke when the game loop stops catching up, see
+    /// [`GameLoop::set_on_catch_up_start`].
+    ///
+    /// The callback isn't carried over when cloning the game loop.
+    pub fn set_on_catch_up_end(&mut self, hook: Box<dyn FnMut() + Send>) {
+        self.on_catch_up_end = Some(CatchUpHook(hook));
+    }
+
+    /// Set a callback to invoke once every real second, with the report of
+    /// the tick that completed the second.
+    ///
+    /// The callback is invoked at the end of the first tick that starts at
+    /// least one second (as measured by the clock of the game loop) after the
+    /// tick that last invoked it, for example to print the frame rate, or to
+    /// take a snapshot of the metrics, without keeping a timer in the game
+    /// state. The first second starts at the first tick after setting the
+    /// callback.
+    ///
+    /// The callback isn't carried over when cloning the game loop.
+    pub fn set_on_second(&mut self, hook: Box<dyn FnMut(&TickReport) + Send>) {
+        self.on_second = Some(SecondHook(hook));
+        self.second_started_at = None;
+    }
+
+    /// Write the report of every tick to the given writer, for offline
+    /// analysis.
+    ///
+    /// Every tick writes a single line of comma-separated values, without a
+    /// header: the amount of updates, whether the tick rendered, whether the
+    /// frame was presented, the [`TickReason`] (in snake case), and the
+    /// accumulated time (in nanoseconds) left by a tick that only rendered.
+    ///
+    /// Writing is best-effort: errors are ignored, so a full disk never stops
+    /// the game. The writer isn't buffered by the game loop, wrap it in a
+    /// [`std::io::BufWriter`] where needed. The writer isn't carried over when
+    /// cloning the game loop.
+    pub fn set_trace_writer(&mut self, writer: Box<dyn Write + Send>) {
+        self.trace_writer = Some(TraceWriter(writer));
+    }
+
+    /// Set the longest (wall-clock) time a single render may take, and a
+    /// callback to invoke with the time a render took, whenever it takes
+    /// longer.
+    ///
+    /// This is the render-side counterpart of detecting that the game loop
+    /// has to catch up with its updates: a game can use the callback to lower
+    /// its rendering quality, keeping the frame time stable. Renders aren't
+    /// timed in deterministic mode, which never reads the clock.
+    ///
+    /// The callback isn't carried over when cloning the game loop.
+    pub fn set_render_budget(
+        &mut self,
+        budget: Duration,
+        on_exceeded: Box<dyn FnMut(Duration) + Send>,
+    ) {
+        self.render_budget = Some(RenderBudget {
+            budget,
+            on_exceeded,
+        });
+    }
+
+    /// Limit the fraction of (wall-clock) time [`GameLoop::run`] keeps the CPU
+    /// busy, for example to save battery, or to share the CPU with other
+    /// processes.
+    ///
+    /// After every tick, the game loop measures how long the tick took, and
+    /// sleeps long enough for the time spent ticking to stay within the given
+    /// fraction of the total time. With a budget of `0.5`, a tick that takes 4
+    /// milliseconds is followed by a sleep of 4 milliseconds. Unlike limiting
+    /// the frame rate, this targets CPU utilization directly: a loop that
+    /// ticks fast enough never sleeps more than it has to. The sleep is timed
+    /// by the clock of the game loop, see [`Clock::sleep`].
+    ///
+    /// Pass `None` to stop limiting the CPU time, which is the default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given fraction isn't greater than `0.0`, and at most `1.0`.
+    pub fn set_cpu_budget(&mut self, fraction: Option<f32>) {
+        if let Some(fraction) = fraction {
+            assert!(
+                fraction > 0.0 && fraction <= 1.0,
+                "the CPU budget must be greater than 0.0, and at most 1.0"
+            );
+        }
+
+        self.cpu_budget = fraction;
+    }
+
+    /// Set the shortest time measured between two ticks.
+    ///
+    /// On fast machines, ticking in a tight loop measures next to no time
+    /// between ticks, so most ticks never update the game state, and only
+    /// keep the CPU spinning. When a tick comes in faster than the given
+    /// floor, the game loop sleeps the difference (see [`Clock::sleep`]), and
+    /// accounts for the floor instead of the measured time. This caps
+    /// busy-spinning, without limiting the frame rate any further.
+    ///
+    /// Defaults to zero, which never sleeps.
+    pub fn set_min_frame_time(&mut self, min_frame_time: Duration) {
+        self.min_frame_time = min_frame_time;
+    }
+
+    /// Set the longest time a single tick accumulates.
+    ///
+    /// After a long stall, such as the game window being dragged, or the
+    /// process being suspended, the game loop would otherwise run a burst of
+    /// updates to catch up with all the time passed. Clamping the frame time
+    /// drops the excess time instead, so the simulation slows down, rather
+    /// than spiralling out of control. See also
+    /// [`GameLoop::set_max_updates_per_tick`], which caps the updates, but
+    /// keeps the excess time around.
+    ///
+    /// Pass `None` to never clamp the frame time, which is the default.
+    pub fn set_max_frame_time(&mut self, max_frame_time: Option<Duration>) {
+        self.max_frame_time = max_frame_time;
+    }
+
+    /// Clamp the given frame time to the longest time a single tick
+    /// accumulates, if any.
+    fn clamp_frame_time(&self, frame_time: Duration) -> Duration {
+        self.max_frame_time
+            .map_or(frame_time, |max_frame_time| frame_time.min(max_frame_time))
+    }
+
+    /// Inject a one-time gap in time, for example to test what happens after
+    /// the game idled for an hour.
+    ///
+    /// The gap is added to the accumulated time, clamped to the configured
+    /// maximum frame time (see [`GameLoop::set_max_frame_time`]), so the next
+    /// tick exercises the catch-up path, just like after a real stall.
+    pub fn skip_time(&mut self, duration: Duration) {
+        self.accumulated_time += self.clamp_frame_time(duration);
+    }
+
+    /// Sleep long enough for the tick started at the given clock reading to
+    /// stay within the CPU budget, if any.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn throttle(&self, started_at: Duration) {
+        let fraction = match self.cpu_budget {
+            Some(fraction) => f64::from(fraction),
+            None => return,
+        };
+
+        let busy = self.clock.now().checked_sub(started_at).unwrap_or_default();
+        let sleep = busy.as_nanos() as f64 * (1.0 - fraction) / fraction;
+        if sleep >= 1.0 {
+            self.clock.sleep(Duration::from_nanos(sleep as u64));
+        }
+    }
+
+    /// Wrap up the game loop after it stopped.
+    fn finish(&mut self) -> Result<(), Error<T>> {
+        if self.shutdown_policy == ShutdownPolicy::DrainUpdates {
+            let max_updates = self.max_updates_per_tick.unwrap_or(usize::max_value());
+            let mut updates = 0;
+            while self.accumulated_time >= self.update_interval && updates < max_updates {
+                self.run_update(NO_CONTEXT)?;
+                self.accumulated_time -= self.update_interval;
+                updates += 1;
+            }
+
+            self.render_now()?;
+        }
+
+        if self.render_on_exit && self.render_pending {
+            let _ = self.run_render(self.render_remainder(0.0), NO_CONTEXT)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get a handle to request the game loop to stop.
+    ///
+    /// See [`StopHandle`] for more details.
+    pub fn stop_handle(&self) -> StopHandle {
+        self.stop_handle.clone()
+    }
+
+    /// A tick is a single "step" forward for the entire state of the game.
     ///
     /// Depending on the game state, calling this method will call the
-    /// `Updater#update` method zero, one or multiple times, and will always
-    /// call the `Renderer#render` method exactly once.
+    /// `Updater#update` method zero, one or multiple times, and will call the
+    /// `Renderer#render` method exactly once, unless rendering is disabled.
+    ///
+    /// See [`GameLoop::tick_report`] to get a report of what happened during
+    /// the tick.
     pub fn tick(&mut self) -> Result<(), Error<T>> {
+        self.tick_report().map(|_| ())
+    }
+
+    /// Advance the clock of the game loop by the given delta, and tick.
+    ///
+    /// See [`GameLoop::advance`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the game loop isn't configured with the
+    /// [`ClockSource::Manual`] clock source.
+    pub fn tick_with_delta(&mut self, delta: Duration) -> Result<(), Error<T>> {
+        self.advance(delta);
+        self.tick()
+    }
+
+    /// Tick once, simulating that exactly the given frame time passed since
+    /// the previous tick, regardless of the time measured by the clock.
+    ///
+    /// This is the primitive to capture a game frame by frame, for example to
+    /// export a video at a fixed frame rate, no matter how long rendering a
+    /// frame actually takes. Unlike [`GameLoop::tick_with_delta`], this works
+    /// with any clock. While paused, the frame time is discarded.
+    pub fn advance_frame(&mut self, frame_time: Duration) -> Result<(), Error<T>> {
+        self.forced_frame_time = Some(frame_time);
+        self.tick()
+    }
+
+    /// Tick once, simulating the time passed between the given timestamp and
+    /// the one supplied to the previous call, regardless of the time measured
+    /// by the clock.
+    ///
+    /// This drives the game loop from a schedule of absolute timestamps, such
+    /// as the ones stored in the event log of a recorded session, see
+    /// [`GameLoop::advance_frame`] to drive it from deltas instead. The first
+    /// call only stores the timestamp to measure the next call against,
+    /// without ticking.
+    ///
+    /// A timestamp earlier than the previous one is handled according to the
+    /// [`BackwardTimePolicy`], like a clock running backwards.
+    pub fn tick_at(&mut self, now: Instant) -> Result<(), Error<T>> {
+        let reference = match self.tick_at_reference.replace(now) {
+            Some(reference) => reference,
+            None => return Ok(()),
+        };
+
+        if now < reference {
+            return match self.backward_time_policy {
+                BackwardTimePolicy::Error => Err(Error::BackwardTime(reference - now)),
+                BackwardTimePolicy::Ignore => self.advance_frame(Duration::default()),
+            };
+        }
+
+        self.advance_frame(now - reference)
+    }
+
+    /// Advance the clock of the game loop by the given delta, without ticking.
+    ///
+    /// The time is accounted for on the next tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the game loop isn't configured with the
+    /// [`ClockSource::Manual`] clock source.
+    pub fn advance(&mut self, delta: Duration) {
+        self.manual_clock
+            .as_ref()
+            .expect("only a game loop with a manual clock source can be advanced")
+            .advance(delta);
+    }
+
+    /// Run a single tick, see [`GameLoop::tick`], and report what happened
+    /// during the tick.
+    pub fn tick_report(&mut self) -> Result<TickReport, Error<T>> {
+        self.run_recorded_tick(NO_CONTEXT, None)
+    }
+
+    /// Run a single tick, see [`GameLoop::tick`], calling the given observers
+    /// right after every update, and every render of the tick.
+    ///
+    /// The update observer receives the sequence number of the update (see
+    /// [`GameLoop::update_sequence`]), and the render observer receives the
+    /// remainder passed to the renderer. This is a lighter alternative to
+    /// persistent hooks, such as [`GameLoop::set_on_update_boundary`], for
+    /// one-off debugging, without modifying the game state.
+    pub fn tick_observed<U, R>(
+        &mut self,
+        mut on_update: U,
+        mut on_render: R,
+    ) -> Result<(), Error<T>>
+    where
+        U: FnMut(u64),
+        R: FnMut(Remainder),
+    {
+        let mut observers = TickObservers {
+            on_update: &mut on_update,
+            on_render: &mut on_render,
+        };
+
+        self.run_recorded_tick(NO_CONTEXT, Some(&mut observers))
+            .map(|_| ())
+    }
+
+    /// Run a single tick, see [`GameLoop::tick`], passing the given context to
+    /// the game state.
+    ///
+    /// This allows the host to thread frame-scoped data, such as a snapshot of
+    /// the input, or the size of the window, into the updates and the render
+    /// of this tick only, see [`Updater::update_with`] and
+    /// [`Renderer::render_with`].
+    pub fn tick_with_context<C>(&mut self, ctx: &C) -> Result<(), Error<T>>
+    where
+        C: Any,
+    {
+        self.run_recorded_tick(Some(ctx), None).map(|_| ())
+    }
+
+    /// Run a single tick, with an optional context and observers, and record
+    /// its report in the metrics.
+    fn run_recorded_tick<C>(
+        &mut self,
+        ctx: Option<&C>,
+        observers: Option<&mut TickObservers<'_>>,
+    ) -> Result<TickReport, Error<T>>
+    where
+        C: Any,
+    {
+        let report = self.run_tick(true, ctx, observers)?;
+        #[cfg(feature = "stats")]
+        self.metrics.record(&report);
+        if let Some(writer) = &mut self.trace_writer {
+            // Tracing is best-effort, a failing writer never stops the game.
+            let _ = report.write_trace_line(&mut *writer.0);
+        }
+        #[cfg(all(feature = "metrics", feature = "stats"))]
+        telemetry::record_tick(&report, self.sustainable_update_rate());
+        #[cfg(all(feature = "metrics", not(feature = "stats")))]
+        telemetry::record_tick(&report, None);
+        self.complete_boosted_tick();
+        self.track_second(&report);
+
+        Ok(report)
+    }
+
+    /// Take the reports of all ticks completed since the last time the metrics
+    /// were drained.
+    ///
+    /// This suits telemetry systems that export metrics in batches. Reports
+    /// are kept for at most 1024 ticks, if the metrics aren't drained in time,
+    /// the oldest reports are dropped, see [`MetricsBatch::dropped`].
+    #[cfg(feature = "stats")]
+    pub fn drain_metrics(&mut self) -> MetricsBatch {
+        self.metrics.drain()
+    }
+
+    /// The highest update rate (in updates per second) the game loop could
+    /// sustain, without having to catch up, given the rolling average time
+    /// between the last 8 ticks.
+    ///
+    /// If every tick takes 10 milliseconds, the game state can be updated at
+    /// most 100 times per second, while still running at most one update per
+    /// tick. This can be used to automatically configure the update rate.
+    ///
+    /// Returns `None` until enough ticks ran to compute the average, or if the
+    /// average time between ticks is zero, such as in deterministic mode,
+    /// which never reads the clock.
+    #[cfg(feature = "stats")]
+    pub fn sustainable_update_rate(&self) -> Option<u32> {
+        let frame_time = self.metrics.average_frame_time()?;
+
+        u128::from(NANOSECONDS_PER_SECOND)
+            .checked_div(frame_time.as_nanos())
+            .and_then(|rate| u32::try_from(rate).ok())
+    }
+
+    /// The update rate (in updates per second) actually achieved over the
+    /// last 8 ticks.
+    ///
+    /// Unlike the configured update rate, this reveals whether the simulation
+    /// keeps up: the achieved rate is lower than configured if the game loop
+    /// falls behind (see [`GameLoop::set_max_updates_per_tick`]), and higher
+    /// during bursts of catching up. Returns `0.0` until the game loop has
+    /// measured any time passing between ticks, such as in deterministic mode,
+    /// which never reads the clock.
+    #[cfg(feature = "stats")]
+    pub fn effective_update_rate(&self) -> f32 {
+        self.metrics.update_rate()
+    }
+
+    /// The longest (wall-clock) time a single update of the game state took,
+    /// since the game loop was created.
+    ///
+    /// Unlike the time spent per tick, which adds up all updates run by the
+    /// tick, this pinpoints a single pathological update. An update divided
+    /// into substeps counts as a single update. Always zero in deterministic
+    /// mode, which never reads the clock.
+    #[cfg(feature = "stats")]
+    pub fn peak_single_update_time(&self) -> Duration {
+        self.metrics.peak_update_time()
+    }
+
+    /// Start the rolling measurements over, without touching the timing of
+    /// the game loop.
+    ///
+    /// After a stall, such as a loading screen, the measurements behind
+    /// [`GameLoop::sustainable_update_rate`],
+    /// [`GameLoop::effective_update_rate`], and
+    /// [`GameLoop::update_render_ratio`] are polluted for the next 8 ticks.
+    /// Resetting them gives clean readings right away. The accumulated time,
+    /// the previous tick, the update sequence, and the collected tick reports
+    /// (see [`GameLoop::drain_metrics`]) are left intact.
+    #[cfg(feature = "stats")]
+    pub fn reset_metrics(&mut self) {
+        self.metrics.clear_frames();
+        self.metrics.clear_phase_times();
+    }
+
+    /// The jitter of the time between ticks, as the standard deviation of the
+    /// time between the last 8 ticks.
+    ///
+    /// Smoothness matters more than the raw frame rate: a game loop with a
+    /// good average frame time, but high jitter, still feels unstable. Returns
+    /// zero until the game loop measured at least two frames.
+    #[cfg(feature = "stats")]
+    pub fn frame_time_jitter(&self) -> Duration {
+        self.metrics.frame_time_jitter()
+    }
+
+    /// The average time of a single update, divided by the average time of a
+    /// single render, over the last 8 updates and renders.
+    ///
+    /// This tells where to optimize: a ratio well above `1.0` means the
+    /// simulation is the bottleneck, a ratio well below `1.0` means rendering
+    /// is. Returns `0.0` until the game loop measured any time rendering, such
+    /// as in deterministic mode, which never reads the clock.
+    #[cfg(feature = "stats")]
+    pub fn update_render_ratio(&self) -> f32 {
+        self.metrics.update_render_ratio()
+    }
+
+    /// The average fraction of the update interval left unused by a single
+    /// update, over the last 8 updates.
+    ///
+    /// A headroom of `1.0` means updating took no time at all, and a headroom
+    /// of `0.0` means updating took (at least) the whole update interval. A
+    /// headroom close to `1.0` means the game loop spends most of its time
+    /// idle, and could run at a higher update rate. Returns `1.0` until the
+    /// game loop measured any updates, such as in deterministic mode, which
+    /// never reads the clock.
+    #[cfg(feature = "stats")]
+    pub fn update_headroom(&self) -> f32 {
+        self.metrics.update_headroom(self.update_interval)
+    }
+
+    /// Whether the recent ticks fit within the frame budget, and if not,
+    /// whether updating or rendering is to blame.
+    ///
+    /// The frame budget is the interval between two renders when limited (see
+    /// [`GameLoop::set_max_render_fps`]), or the update interval otherwise. A
+    /// tick costs the average time of a single update, times the average
+    /// amount of updates per tick, plus the average time of a single render,
+    /// over the last 8 ticks. A game can use this to tune its simulation or
+    /// rendering quality. Always [`Bottleneck::WithinBudget`] in
+    /// deterministic mode, which never reads the clock.
+    #[cfg(feature = "stats")]
+    pub fn bottleneck(&self) -> Bottleneck {
+        let budget = self.min_render_interval.unwrap_or(self.update_interval);
+
+        self.metrics.bottleneck(budget)
+    }
+
+    /// The amount of updates that corrected a misprediction of the
+    /// interpolation, since the game loop was created.
+    ///
+    /// See [`Updater::interpolation_corrected`].
+    #[cfg(feature = "stats")]
+    pub fn stutter_count(&self) -> usize {
+        self.metrics.stutter_count()
+    }
+
+    /// Bring the game state up to date with the clock, without rendering it.
+    ///
+    /// This measures the time since the last tick, and updates the game state
+    /// until the accumulated time is drained (respecting the maximum updates
+    /// per tick), returning the amount of updates that ran. This is the update
+    /// half of a tick, for games that only tick on demand, for example when
+    /// handling input events.
+    pub fn catch_up(&mut self) -> Result<usize, Error<T>> {
+        self.run_tick(false, NO_CONTEXT, None)
+            .map(|report| report.updates)
+    }
+
+    /// Drive the state machine of a single tick to completion, optionally
+    /// skipping the render, passing the context (if any) to the game state,
+    /// and notifying the observers (if any).
+    fn run_tick<C>(
+        &mut self,
+        render: bool,
+        ctx: Option<&C>,
+        observers: Option<&mut TickObservers<'_>>,
+    ) -> Result<TickReport, Error<T>>
+    where
+        C: Any,
+    {
+        #[cfg(not(feature = "tracing"))]
+        return self.drive_tick(render, ctx, observers);
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = spans::tick_span();
+            let _entered = span.enter();
+
+            let result = self.drive_tick(render, ctx, observers);
+
+            // Only completed ticks are recorded, a failed update or render
+            // leaves the previous tick in place.
+            if let Ok(_) | Err(Error::BackwardTime(_)) = &result {
+                if let Some(tick) = &self.previous_tick {
+                    spans::record_tick(&span, tick);
+                }
+            }
+
+            result
+        }
+    }
+
+    /// The state machine of a single tick, see [`GameLoop::run_tick`].
+    fn drive_tick<C>(
+        &mut self,
+        render: bool,
+        ctx: Option<&C>,
+        mut observers: Option<&mut TickObservers<'_>>,
+    ) -> Result<TickReport, Error<T>>
+    where
+        C: Any,
+    {
         use State::*;
 
         // Create a new tick instance, to keep track of this tick's progress.
-        let mut tick = Tick::default();
-        debug_assert_eq!(tick.state, Idle);
+        // A deterministic loop never reads the clock.
+        let started_at = if self.deterministic {
+            Duration::default()
+        } else {
+            let now = self.clock.now();
+            let _ = self.first_tick_at.get_or_insert(now);
+            now
+        };
+        let mut tick = Tick::new(started_at);
+        let forced_frame_time = self.forced_frame_time.take();
+        let skip_frame_time = std::mem::replace(&mut self.skip_next_frame_time, false);
+        self.prune_recent_updates(&tick);
+
+        // Rate changes requested by the game state only take effect at the
+        // start of a tick, never while draining the accumulated time.
+        self.apply_desired_update_rate();
+
+        if self.auto_paused && self.state.is_active() {
+            self.resume();
+        }
 
         // We'll continue to drive the game state forward, until we've completed
         // all the work for this tick.
         loop {
+            self.phase = Phase::from(tick.state);
+
             match tick.state {
                 // The tick is about to start running, so we check how long ago
                 // the last tick ran, to determine the speed of the game loop,
                 // and set the amount of times the updater should run to catch
                 // up.
+                //
+                // A clock running backwards is ignored, no time is added to the
+                // accumulator in that case, unless configured to report it as
+                // an error.
+                //
+                // A deterministic loop instead simulates that exactly one
+                // update interval has passed since the last tick, and a frame
+                // advanced by hand simulates the given frame time.
+                //
+                // While paused, no time accumulates, and we skip straight to
+                // rendering.
+                Idle if self.paused => {
+                    let elapsed = if skip_frame_time {
+                        Duration::default()
+                    } else {
+                        self.paused_elapsed(&tick, forced_frame_time)
+                    };
+                    let elapsed = self.render_elapsed(elapsed);
+                    if self.min_render_interval.is_some() {
+                        self.render_time += elapsed;
+                    }
+
+                    tick.state = Rendering;
+                }
+
                 Idle => {
-                    if let Some(tick) = &self.previous_tick {
-                        let previous_tick_duration = tick.started_at.elapsed();
-                        self.accumulated_time += previous_tick_duration;
+                    let elapsed = if let Some(frame_time) = forced_frame_time {
+                        tick.frame_time = Some(frame_time);
+                        frame_time
+                    } else if self.deterministic {
+                        self.update_interval
+                    } else if skip_frame_time {
+                        // The first tick after a suspension ignores the gap,
+                        // just like the very first tick.
+                        Duration::default()
+                    } else if let Some(previous_started_at) =
+                        self.previous_tick.as_ref().map(|tick| tick.started_at)
+                    {
+                        let mut previous_tick_duration =
+                            match tick.started_at.checked_sub(previous_started_at) {
+                                Some(duration) => duration,
+                                None if self.backward_time_policy == BackwardTimePolicy::Error => {
+                                    let went_back = previous_started_at - tick.started_at;
+                                    let _ = self.complete_tick(tick);
+
+                                    return Err(Error::BackwardTime(went_back));
+                                }
+                                None => Duration::default(),
+                            };
+
+                        // A tick that came in too fast waits for the floor,
+                        // and starts once done waiting, so the next tick
+                        // doesn't measure the wait a second time.
+                        if let Some(wait) = self.min_frame_time.checked_sub(previous_tick_duration)
+                        {
+                            if wait > Duration::default() {
+                                self.clock.sleep(wait);
+                                tick.started_at += wait;
+                                previous_tick_duration = self.min_frame_time;
+                            }
+                        }
+                        tick.frame_time = Some(previous_tick_duration);
+                        previous_tick_duration
+                    } else {
+                        // The very first tick has nothing to measure against,
+                        // so the time before it, such as constructing the
+                        // loop, never leaks into the simulation.
+                        Duration::default()
+                    };
+
+                    let elapsed = self.clamp_frame_time(elapsed);
+                    let render_elapsed = self.render_elapsed(elapsed);
+                    if self.min_render_interval.is_some() {
+                        self.render_time += render_elapsed;
                     }
 
-                    tick.state = Updating;
-                }
+                    match self.timestep_mode {
+                        TimestepMode::Fixed => self.accumulated_time += elapsed,
+                        TimestepMode::Variable if elapsed > Duration::default() => {
+                            tick.variable_step = Some(elapsed);
+                        }
+                        TimestepMode::Variable => {}
+                    }
+                    self.clamp_accumulated_time();
+
+                    tick.state = Updating;
+                }
+
+                // If enough time has accumulated since the last tick, run the
+                // updater, until it has drained the accumulated time.
+                //
+                // The required accumulated time depends on the configured
+                // updates per second. If set to 100, we have a budget of 10
+                // milliseconds per update, so `accumulated_time` needs to be 10
+                // milliseconds or more to perform another update.
+                //
+                // After updating the game, we keep the [`GameState`] set to
+                // `Updating`, and we try to update the game again, until we run
+                // out of `accumuated_time`, or reach the maximum amount of
+                // updates per tick.
+                //
+                // If a stop is requested in the meantime, we abandon the tick
+                // right away, without rendering the updated game state.
+                //
+                // In the variable timestep mode, we instead run a single update
+                // simulating all the time passed since the last tick.
+                Updating if self.update_due(&tick) || tick.variable_step.is_some() => {
+                    if self.stop_handle.is_stopped() {
+                        return Ok(self.complete_tick(tick));
+                    }
+
+                    if let Some(step) = tick.variable_step.take() {
+                        self.run_tick_update(&mut tick, Some(step), ctx, &mut observers)?;
+                    } else {
+                        self.run_tick_update(&mut tick, None, ctx, &mut observers)?;
+                        self.accumulated_time -= self.update_interval;
+                    }
+                }
+
+                // Once we run out of time to update the game state, move on to
+                // rendering.
+                Updating => {
+                    tick.state = Rendering;
+                }
+
+                // Call the renderer.
+                //
+                // While the `accumulated_time` budget wasn't large enough to
+                // perform another game update, chances are it wasn't exactly
+                // zero once we were done updating the game. This means we're
+                // about to render the game in-between two game updates.
+                //
+                // We pass the "remainder" (a value between 0.0 and 1.0) between
+                // the last update, and the expected next update to the
+                // [`Renderer`], to allow for visual interpolation of the game
+                // state, clamped to the configured maximum interpolation.
+                //
+                // If rendering is disabled, or this isn't one of the ticks
+                // configured to render, we skip the renderer, and the tick
+                // reports it didn't render.
+                Rendering => {
+                    if render && self.render_enabled && self.render_due() {
+                        let remainder = self.current_render_remainder();
+                        let mut outcome = self.run_render(remainder, ctx)?;
+                        self.consume_render_time();
+                        if outcome.is_none() {
+                            tick.suppressed_render_errors += 1;
+                        } else if let Some(observers) = &mut observers {
+                            (observers.on_render)(remainder);
+                        }
+
+                        // Extra updates are held to the same rules as regular
+                        // updates, except they don't drain accumulated time.
+                        let mut extra_updates = 0;
+                        while outcome.map_or(false, |outcome| outcome.request_extra_update)
+                            && extra_updates < MAX_EXTRA_UPDATES
+                            && !self.stop_handle.is_stopped()
+                            && self.update_allowed()
+                        {
+                            self.run_tick_update(&mut tick, None, ctx, &mut observers)?;
+                            extra_updates += 1;
+
+                            outcome = self.run_render(remainder, ctx)?;
+                            if outcome.is_none() {
+                                tick.suppressed_render_errors += 1;
+                            } else if let Some(observers) = &mut observers {
+                                (observers.on_render)(remainder);
+                            }
+                        }
+
+                        // A swallowed render error means no frame was
+                        // rendered, so there's nothing to present either.
+                        if let Some(outcome) = outcome {
+                            tick.rendered = true;
+                            tick.presented = outcome.presented;
+                        }
+                    } else {
+                        self.ticks_since_render += 1;
+                    }
+
+                    // We're done with this tick, exit the method.
+                    return Ok(self.complete_tick(tick));
+                }
+            }
+        }
+    }
+
+    /// Wrap up the given tick, returning its report.
+    fn complete_tick(&mut self, tick: Tick) -> TickReport {
+        #[cfg(feature = "stats")]
+        {
+            if let Some(frame_time) = tick.frame_time {
+                self.metrics.record_frame(frame_time, tick.updates);
+            }
+        }
+
+        self.track_catch_up(tick.updates);
+
+        let report = self.report(&tick);
+        self.previous_tick = Some(tick);
+        self.phase = Phase::Idle;
+
+        report
+    }
+
+    /// Enter or leave the catch-up regime, given the amount of updates run by
+    /// the last tick, invoking the matching callback on every transition.
+    fn track_catch_up(&mut self, updates: usize) {
+        if (updates > 1) == self.catching_up {
+            self.catch_up_streak = 0;
+            return;
+        }
+
+        self.catch_up_streak += 1;
+        if self.catch_up_streak < CATCH_UP_TICKS {
+            return;
+        }
+
+        self.catch_up_streak = 0;
+        self.catching_up = !self.catching_up;
+
+        let hook = if self.catching_up {
+            &mut self.on_catch_up_start
+        } else {
+            &mut self.on_catch_up_end
+        };
+        if let Some(hook) = hook {
+            (hook.0)();
+        }
+    }
+
+    /// Invoke the callback set through [`GameLoop::set_on_second`] with the
+    /// given report of the last tick, if a second passed since the tick that
+    /// last invoked it.
+    fn track_second(&mut self, report: &TickReport) {
+        let hook = match &mut self.on_second {
+            Some(hook) => hook,
+            None => return,
+        };
+        let now = match &self.previous_tick {
+            Some(tick) => tick.started_at,
+            None => return,
+        };
+
+        let started_at = *self.second_started_at.get_or_insert(now);
+        if now.checked_sub(started_at).unwrap_or_default() >= Duration::from_secs(1) {
+            self.second_started_at = Some(now);
+            (hook.0)(report);
+        }
+    }
+
+    /// The report of the given tick.
+    fn report(&self, tick: &Tick) -> TickReport {
+        TickReport {
+            updates: tick.updates,
+            rendered: tick.rendered,
+            presented: tick.presented,
+            suppressed_render_errors: tick.suppressed_render_errors,
+            reason: self.tick_reason(tick),
+            label: self.label.clone(),
+        }
+    }
+
+    /// The reason the given tick played out the way it did, see
+    /// [`TickReason`].
+    fn tick_reason(&self, tick: &Tick) -> TickReason {
+        if self.paused {
+            TickReason::Paused
+        } else if self.accumulated_time >= self.update_interval && !self.update_due(tick) {
+            TickReason::CappedCatchUp
+        } else if tick.updates > 0 && tick.presented {
+            TickReason::UpdatedAndRendered
+        } else if tick.updates > 0 {
+            TickReason::UpdatedOnly
+        } else {
+            TickReason::RenderedOnly(self.accumulated_time)
+        }
+    }
+
+    /// Run a single update as part of the given tick, simulating the given
+    /// step, or one update interval.
+    ///
+    /// The update boundary hook is invoked before the update, and the update
+    /// is counted towards the tick and the update ceiling (see
+    /// [`GameLoop::set_max_updates_per_real_second`]) after.
+    fn run_tick_update<C>(
+        &mut self,
+        tick: &mut Tick,
+        step: Option<Duration>,
+        ctx: Option<&C>,
+        observers: &mut Option<&mut TickObservers<'_>>,
+    ) -> Result<(), Error<T>>
+    where
+        C: Any,
+    {
+        if let Some(hook) = &mut self.on_update_boundary {
+            (hook.0)(u64::try_from(tick.updates).unwrap_or(u64::max_value()));
+        }
+
+        match step {
+            Some(step) => self.run_update_over(step, ctx)?,
+            None => self.run_update(ctx)?,
+        }
+        tick.updates += 1;
+        if self.max_updates_per_real_second.is_some() {
+            self.recent_updates.push_back(tick.started_at);
+        }
+
+        if let Some(observers) = observers {
+            (observers.on_update)(self.update_sequence);
+        }
+
+        Ok(())
+    }
+
+    /// Whether another update is due during the given tick.
+    fn update_due(&self, tick: &Tick) -> bool {
+        self.accumulated_time >= self.update_interval
+            && self
+                .max_updates_per_tick
+                .map_or(true, |max_updates| tick.updates < max_updates)
+            && self.update_allowed()
+    }
+
+    /// Whether the game state may be updated at all: the game loop isn't
+    /// paused, and hasn't reached its update ceiling, see
+    /// [`GameLoop::set_max_updates_per_real_second`].
+    fn update_allowed(&self) -> bool {
+        !self.paused
+            && self
+                .max_updates_per_real_second
+                .map_or(true, |max_updates| {
+                    self.deterministic || self.recent_updates.len() < max_updates as usize
+                })
+    }
+
+    /// Forget the updates that ran more than a second of (clock) time before
+    /// the given tick, see [`GameLoop::set_max_updates_per_real_second`].
+    fn prune_recent_updates(&mut self, tick: &Tick) {
+        while let Some(&started_at) = self.recent_updates.front() {
+            match tick.started_at.checked_sub(started_at) {
+                Some(age) if age < Duration::from_secs(1) => break,
+                // A clock running backwards invalidates the window as well.
+                Some(_) | None => {
+                    let _ = self.recent_updates.pop_front();
+                }
+            }
+        }
+    }
+
+    /// Set the maximum amount of updates per tick, or `None` to not limit the
+    /// amount of updates.
+    ///
+    /// If the game can't keep up with the configured update rate, every tick
+    /// accumulates more time than it can drain, causing ever longer ticks,
+    /// and ever more time to catch up with (also known as the "spiral of
+    /// death"). Capping the amount of updates per tick prevents this. Any
+    /// accumulated time left after reaching the cap is carried over to the
+    /// next tick. Uncapped by default.
+    ///
+    /// Note that while catching up, the remainder passed to the renderer is
+    /// clamped by the maximum interpolation, see
+    /// [`GameLoop::set_max_interpolation`].
+    pub fn set_max_updates_per_tick(&mut self, max_updates: Option<usize>) {
+        self.max_updates_per_tick = max_updates;
+    }
+
+    /// The maximum amount of updates per tick, or `None` if the amount of
+    /// updates isn't limited, see [`GameLoop::set_max_updates_per_tick`].
+    pub fn max_updates_per_tick(&self) -> Option<usize> {
+        self.max_updates_per_tick
+    }
+
+    /// Limit the accumulated time to the given amount of update intervals, or
+    /// `None` to not limit the accumulated time.
+    ///
+    /// At the start of every tick, after adding the time passed since the
+    /// previous tick, any accumulated time beyond the given amount of update
+    /// intervals is discarded. After a stall, the game loop runs at most that
+    /// many updates to catch up, and the rest of the lost time is gone for
+    /// good. Unlike [`GameLoop::set_max_frame_time`], this is expressed in
+    /// updates, which makes catch-up bursts easier to reason about. Unlimited
+    /// by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given amount of intervals is zero, or doesn't fit in a
+    /// `u32`.
+    pub fn set_max_accumulated_intervals(&mut self, intervals: Option<usize>) {
+        self.max_accumulated_intervals = intervals.map(|intervals| {
+            assert!(intervals > 0, "the accumulated time has to fit an update");
+            u32::try_from(intervals).expect("the amount of intervals must fit in a u32")
+        });
+    }
+
+    /// Discard any accumulated time beyond the maximum amount of update
+    /// intervals, see [`GameLoop::set_max_accumulated_intervals`].
+    fn clamp_accumulated_time(&mut self) {
+        if let Some(intervals) = self.max_accumulated_intervals {
+            self.accumulated_time = self.accumulated_time.min(self.update_interval * intervals);
+        }
+    }
+
+    /// Set the maximum amount of updates per second of (clock) time, across
+    /// all ticks, or `None` to not limit the amount of updates.
+    ///
+    /// Unlike [`GameLoop::set_max_updates_per_tick`], this is a global
+    /// ceiling: once the given amount of updates ran during the last second,
+    /// no tick updates the game state, until the oldest of those updates is
+    /// more than a second ago. The accumulated time is carried over, like any
+    /// capped catch-up. This protects a shared server from a single runaway
+    /// simulation. The ceiling doesn't apply in deterministic mode, which
+    /// never reads the clock. Unlimited by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given amount of updates is zero.
+    pub fn set_max_updates_per_real_second(&mut self, max_updates: Option<u32>) {
+        if let Some(max_updates) = max_updates {
+            assert!(
+                max_updates > 0,
+                "the game loop has to update at least once per second"
+            );
+        }
+
+        self.max_updates_per_real_second = max_updates;
+        if max_updates.is_none() {
+            self.recent_updates.clear();
+        }
+    }
+
+    /// Pause updating the game state.
+    ///
+    /// While paused, ticks keep rendering the game state, but don't update it.
+    /// Time spent paused doesn't accumulate, so resuming doesn't trigger a
+    /// burst of updates to catch up with the time spent paused.
+    ///
+    /// The game state is notified through [`Updater::on_pause`], unless the
+    /// loop was already paused.
+    ///
+    /// Pausing a loop that was paused automatically (see
+    /// [`GameLoop::set_auto_pause_after`]) turns it into an explicit pause,
+    /// which only [`GameLoop::resume`] ends.
+    pub fn pause(&mut self) {
+        self.auto_paused = false;
+        if !self.paused {
+            self.paused = true;
+            self.state.on_pause();
+        }
+    }
+
+    /// Resume updating the game state, after it was paused, or suspended.
+    ///
+    /// The game state is notified through [`Updater::on_resume`], unless the
+    /// loop wasn't paused. After a suspension (see [`GameLoop::suspend`]), the
+    /// time passed since the last tick is ignored entirely.
+    pub fn resume(&mut self) {
+        if self.suspended {
+            self.suspended = false;
+            self.skip_next_frame_time = true;
+            self.render_clock_read_at = None;
+        }
+
+        self.auto_paused = false;
+        if self.paused {
+            self.paused = false;
+            self.state.on_resume();
+        }
+    }
+
+    /// Whether updating the game state is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Suspend the game loop, for example when the operating system suspends
+    /// the application.
+    ///
+    /// This pauses the game loop (see [`GameLoop::pause`]), notifying the game
+    /// state through [`Updater::on_pause`]. Unlike a pause, during which ticks
+    /// keep measuring the time between them, a suspended application doesn't
+    /// tick at all, so the first tick after the suspension would measure the
+    /// whole gap. Instead, the first tick after [`GameLoop::resume`] doesn't
+    /// measure the time since the last tick, so the gap is ignored entirely,
+    /// no matter how long it was, which is stronger than clamping the frame
+    /// time (see [`GameLoop::set_max_frame_time`]).
+    pub fn suspend(&mut self) {
+        self.suspended = true;
+        self.pause();
+    }
+
+    /// Whether the game loop is suspended, see [`GameLoop::suspend`].
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
+
+    /// Pause the game loop automatically, once the game reported itself
+    /// inactive for the given amount of simulated time, for example to run a
+    /// screensaver-style demo.
+    ///
+    /// After every update, the game loop polls [`Updater::is_active`]. Once
+    /// the game was inactive long enough, the game loop pauses (see
+    /// [`GameLoop::pause`]). While automatically paused, the game loop keeps
+    /// polling the game at the start of every tick, and resumes as soon as
+    /// the game reports itself active again. An explicit pause (see
+    /// [`GameLoop::pause`]), or suspension, is never resumed automatically.
+    ///
+    /// Pass `None` to never pause automatically, which is the default.
+    pub fn set_auto_pause_after(&mut self, idle_time: Option<Duration>) {
+        self.auto_pause_after = idle_time;
+        self.idle_time = Duration::default();
+    }
+
+    /// Enable or disable rendering the game state.
+    ///
+    /// While disabled, ticks keep updating the game state, but skip rendering
+    /// it, for example while the game window is hidden. Enabled by default.
+    pub fn set_render_enabled(&mut self, render_enabled: bool) {
+        self.render_enabled = render_enabled;
+    }
+
+    /// Only render the game state on every `n`th tick.
+    ///
+    /// The other ticks still update the game state as usual, but skip
+    /// rendering it. This suits headless simulations that run as fast as
+    /// possible, and only need an occasional frame. Unlike limiting the frame
+    /// rate, this is counted in ticks, not time. Defaults to 1, rendering every
+    /// tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn set_render_every(&mut self, n: usize) {
+        assert!(n > 0, "the game loop has to render at least every tick");
+
+        self.render_every = n;
+    }
+
+    /// The sequence number of the last update of the game state.
+    ///
+    /// The sequence number starts at zero, and increments with every update
+    /// (not every substep), including the updates run by
+    /// [`GameLoop::fast_forward`]. It is derived purely from the amount of
+    /// updates, never from a clock, so two runs driven by the same sequence of
+    /// time deltas end up with the same sequence number. This gives replays a
+    /// deterministic cursor.
+    pub fn update_sequence(&self) -> u64 {
+        self.update_sequence
+    }
+
+    /// The time simulated by the updates of the game state, since the game
+    /// loop was created.
+    ///
+    /// Every update adds the time it simulated: the update interval, or the
+    /// variable step (see [`GameLoop::set_timestep_mode`]). Like
+    /// [`GameLoop::update_sequence`], this is derived from the updates, never
+    /// from a clock.
+    pub fn simulation_time(&self) -> Duration {
+        self.simulation_time
+    }
+
+    /// A fingerprint of the timing state of the game loop.
+    ///
+    /// The fingerprint hashes the accumulated time, the update interval, and
+    /// the update sequence (see [`GameLoop::update_sequence`]), so two game
+    /// loops driven by the same sequence of time deltas end up with the same
+    /// fingerprint. This gives tests a one-line determinism check.
+    /// Fingerprints are only comparable within the same build of the game.
+    pub fn timing_fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.accumulated_time.as_nanos().hash(&mut hasher);
+        self.update_interval.as_nanos().hash(&mut hasher);
+        self.update_sequence.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// Whether the game loop completed at least one tick.
+    ///
+    /// This suits initialization logic that runs lazily, before the first
+    /// tick. A clone of the game loop starts out without any ticks.
+    pub fn has_ticked(&self) -> bool {
+        self.previous_tick.is_some()
+    }
+
+    /// The amount of ticks completed since the game state was last rendered.
+    ///
+    /// The counter resets whenever the renderer is called, including through
+    /// [`GameLoop::render_now`]. A host can use this to force a render, when
+    /// too many ticks passed without one, for example while rendering only
+    /// every few ticks (see [`GameLoop::set_render_every`]).
+    pub fn ticks_since_render(&self) -> usize {
+        self.ticks_since_render
+    }
+
+    /// Whether the current tick is due to render, see
+    /// [`GameLoop::set_render_every`] and [`GameLoop::set_max_render_fps`].
+    fn render_due(&self) -> bool {
+        self.ticks_since_render + 1 >= self.render_every
+            && self
+                .min_render_interval
+                .map_or(true, |interval| self.render_time >= interval)
+    }
+
+    /// Consume the render time of a single render, see
+    /// [`GameLoop::set_max_render_fps`].
+    ///
+    /// A render that is (very) late doesn't allow a burst of renders to catch
+    /// up, the render time starts over instead.
+    fn consume_render_time(&mut self) {
+        if let Some(interval) = self.min_render_interval {
+            self.render_time = self.render_time.checked_sub(interval).unwrap_or_default();
+            if self.render_time >= interval {
+                self.render_time = Duration::default();
+            }
+        }
+    }
+
+    /// The time passed since the previous tick as far as pacing the renders is
+    /// concerned: the given time measured by the clock of the game loop, or
+    /// the time measured by the render clock, if configured.
+    fn render_elapsed(&mut self, elapsed: Duration) -> Duration {
+        let now = match &self.render_clock {
+            Some(render_clock) => render_clock.now(),
+            None => return elapsed,
+        };
+
+        let elapsed = self
+            .render_clock_read_at
+            .and_then(|read_at| now.checked_sub(read_at))
+            .unwrap_or_default();
+        self.render_clock_read_at = Some(now);
+
+        elapsed
+    }
+
+    /// The time passed since the previous tick, measured by a paused tick,
+    /// which doesn't accumulate any time for the updates.
+    fn paused_elapsed(&self, tick: &Tick, forced_frame_time: Option<Duration>) -> Duration {
+        if let Some(frame_time) = forced_frame_time {
+            frame_time
+        } else if self.deterministic {
+            self.update_interval
+        } else {
+            self.previous_tick
+                .as_ref()
+                .and_then(|previous_tick| tick.started_at.checked_sub(previous_tick.started_at))
+                .unwrap_or_default()
+        }
+    }
+
+    /// Never render more than the given amount of times per second.
+    ///
+    /// A tick that comes in sooner than that after the last render still
+    /// updates the game state, but skips rendering it. This is a coarse
+    /// power-saving measure, that doesn't sleep, and doesn't pace the ticks.
+    /// Unlike [`GameLoop::set_render_every`], this is counted in time, not in
+    /// ticks. The first tick after setting the limit always renders.
+    ///
+    /// Pass `None` to stop limiting the renders, which is the default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given amount of renders per second is zero.
+    pub fn set_max_render_fps(&mut self, fps: Option<u32>) {
+        self.min_render_interval = fps.map(|fps| {
+            assert!(
+                fps > 0,
+                "the game loop has to render at least once per second"
+            );
+            interval_from_rate(fps)
+        });
+        self.render_time = self.min_render_interval.unwrap_or_default();
+    }
+
+    /// The time left until the next render is due, if the renders are limited
+    /// (see [`GameLoop::set_max_render_fps`]).
+    ///
+    /// This allows a host that paces its frames itself, for example by
+    /// waiting for vsync, to know when the game loop renders next. Returns
+    /// zero when a render is already due, and `None` when the renders aren't
+    /// limited.
+    pub fn time_to_next_render(&self) -> Option<Duration> {
+        self.min_render_interval
+            .map(|interval| interval.checked_sub(self.render_time).unwrap_or_default())
+    }
+
+    /// Choose whether the renderer interpolates between the last two updates,
+    /// or extrapolates beyond the last update.
+    ///
+    /// With the (default) [`RenderMode::Interpolate`] mode, the remainder
+    /// passed to the renderer is relative to the last update, between `0.0`
+    /// and `1.0`, and the renderer blends the previous update into the last
+    /// one. The rendered frame is accurate, but lags up to one update interval
+    /// behind the simulation.
+    ///
+    /// With [`RenderMode::Extrapolate`], the remainder is relative to the
+    /// update before the last one instead, between `1.0` and `2.0`, so the
+    /// same blend (see [`interpolate_between`]) projects the game state ahead
+    /// of the last update. This minimizes input latency, at the cost of
+    /// accuracy: when the next update contradicts the prediction, the
+    /// rendered frame visibly corrects itself, see
+    /// [`Updater::interpolation_corrected`].
+    pub fn set_render_mode(&mut self, mode: RenderMode) {
+        self.render_mode = mode;
+    }
+
+    /// The remainder passed to the renderer, given the remainder relative to
+    /// the last update, see [`GameLoop::set_render_mode`].
+    fn render_remainder(&self, remainder: Remainder) -> Remainder {
+        match self.render_mode {
+            RenderMode::Interpolate => remainder,
+            RenderMode::Extrapolate => 1.0 + remainder,
+        }
+    }
+
+    /// The remainder passed to the renderer right now, clamped to the
+    /// configured maximum interpolation, and offset by the render mode.
+    fn current_render_remainder(&self) -> Remainder {
+        self.render_remainder(self.raw_remainder().min(self.max_interpolation))
+    }
+
+    /// Switch between a fixed and a variable timestep at runtime.
+    ///
+    /// With the (default) [`TimestepMode::Fixed`] mode, every update simulates
+    /// exactly one update interval. With [`TimestepMode::Variable`], every
+    /// tick runs a single update, which simulates all the time passed since
+    /// the previous tick (passed to [`Updater::update_step`]), and the
+    /// renderer always receives a remainder of `0.0`. The variable mode suits
+    /// parts of the game where exact simulation doesn't matter, such as
+    /// cutscenes.
+    ///
+    /// Switching into the variable mode discards the accumulated time, so no
+    /// stale fixed-step time leaks into the variable steps.
+    pub fn set_timestep_mode(&mut self, mode: TimestepMode) {
+        if mode == TimestepMode::Variable && self.timestep_mode == TimestepMode::Fixed {
+            self.accumulated_time = Duration::default();
+        }
+
+        self.timestep_mode = mode;
+    }
+
+    /// Update the game state the given number of times, back-to-back.
+    ///
+    /// This is meant to warm-start a simulation, for example after loading a
+    /// saved game, by running it forward without any regard for time: the
+    /// accumulated time is left untouched, and the game state isn't rendered.
+    pub fn fast_forward(&mut self, updates: usize) -> Result<(), Error<T>> {
+        for _ in 0..updates {
+            self.run_update(NO_CONTEXT)?;
+        }
+
+        Ok(())
+    }
+
+    /// Update the game state back-to-back, until the simulation time (see
+    /// [`GameLoop::simulation_time`]) reaches the given target, returning the
+    /// amount of updates that ran.
+    ///
+    /// This suits lockstep networking, to catch a client up with the
+    /// simulation time of the server. Like [`GameLoop::fast_forward`], the
+    /// accumulated time is left untouched, and the game state isn't rendered.
+    /// To prevent a runaway, at most 1024 updates run per call, leaving the
+    /// simulation behind the target if it is that far off.
+    pub fn catch_up_to(&mut self, target_sim_time: Duration) -> Result<usize, Error<T>> {
+        let mut updates = 0;
+        while self.simulation_time < target_sim_time && updates < MAX_CATCH_UP_TO_UPDATES {
+            self.run_update(NO_CONTEXT)?;
+            updates += 1;
+        }
+
+        Ok(updates)
+    }
+
+    /// Update the game state the given number of times, back-to-back, and
+    /// render the result once, with a remainder of `0.0`.
+    ///
+    /// This suits turn-based games, that advance the simulation by a number of
+    /// steps at a time, and show the result. Like [`GameLoop::fast_forward`],
+    /// the accumulated time is left untouched. The game state is rendered even
+    /// if rendering is disabled, and with a remainder of `0.0` regardless of
+    /// the render mode (see [`GameLoop::set_render_mode`]).
+    pub fn advance_and_render(&mut self, steps: usize) -> Result<(), Error<T>> {
+        self.fast_forward(steps)?;
+        let _ = self.run_render(0.0, NO_CONTEXT)?;
+
+        Ok(())
+    }
+
+    /// Divide every update into the given amount of substeps.
+    ///
+    /// Stiff physics simulations sometimes need smaller steps than the update
+    /// interval to stay stable. With more than one substep, every update that
+    /// drains the update interval from the accumulated time calls
+    /// [`Updater::update_substep`] multiple times, each with an equal fraction
+    /// of the update interval. A game state has to implement that method to
+    /// benefit from substeps, by default it's updated once per update, as if
+    /// there were no substeps. Defaults to 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of substeps is zero, or doesn't fit in a `u32`.
+    pub fn set_update_substeps(&mut self, substeps: usize) {
+        assert!(substeps > 0, "an update needs at least one substep");
+
+        self.update_substeps =
+            u32::try_from(substeps).expect("the amount of substeps must fit in a u32");
+    }
+
+    /// Update the game state once, in as many substeps as configured, with the
+    /// given context (if any).
+    fn run_update<C>(&mut self, ctx: Option<&C>) -> Result<(), Error<T>>
+    where
+        C: Any,
+    {
+        self.run_update_over(self.update_interval, ctx)
+    }
+
+    /// Update the game state once, simulating the given amount of time, in as
+    /// many substeps as configured, with the given context (if any).
+    fn run_update_over<C>(&mut self, time: Duration, ctx: Option<&C>) -> Result<(), Error<T>>
+    where
+        C: Any,
+    {
+        #[cfg(feature = "tracing")]
+        let span = spans::update_span(self.update_sequence + 1);
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        // The deterministic mode never reads the clock, so updates aren't
+        // timed either. Updates are only timed to collect metrics, or to trace
+        // their duration.
+        #[cfg(any(feature = "stats", feature = "tracing"))]
+        let started_at = if self.deterministic {
+            None
+        } else {
+            Some(self.clock.now())
+        };
+
+        let substeps = self.update_substeps;
+        match ctx {
+            Some(ctx) => self.state.update_with(time, ctx),
+            None if substeps == 1 => self.state.update_step(time),
+            None => (0..substeps).try_for_each(|substep| {
+                self.state
+                    .update_substep(time / substeps, substep, substeps)
+            }),
+        }
+        .map_err(Error::Update)?;
+        self.render_pending = true;
+        self.update_sequence += 1;
+        self.simulation_time += time;
+
+        #[cfg(any(feature = "stats", feature = "tracing"))]
+        {
+            if let Some(started_at) = started_at {
+                let time = self.clock.now().checked_sub(started_at).unwrap_or_default();
+                #[cfg(feature = "stats")]
+                self.metrics.record_update_time(time);
+                #[cfg(feature = "tracing")]
+                spans::record_duration(&span, time);
+            }
+        }
+
+        if let Some(threshold) = self.auto_pause_after {
+            if self.state.is_active() {
+                self.idle_time = Duration::default();
+            } else {
+                self.idle_time += time;
+                // An explicitly paused loop, for example while fast-forwarding,
+                // stays paused explicitly.
+                if self.idle_time >= threshold && !self.paused {
+                    self.idle_time = Duration::default();
+                    self.pause();
+                    self.auto_paused = true;
+                }
+            }
+        }
+
+        #[cfg(feature = "stats")]
+        {
+            if self.state.interpolation_corrected() {
+                self.metrics.record_stutter();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render the game state once, with the given remainder and context (if
+    /// any), returning the outcome of the render.
+    fn run_render<C>(
+        &mut self,
+        remainder: Remainder,
+        ctx: Option<&C>,
+    ) -> Result<Option<RenderOutcome>, Error<T>>
+    where
+        C: Any,
+    {
+        #[cfg(feature = "tracing")]
+        let span = spans::render_span(remainder);
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        // The deterministic mode never reads the clock, so renders aren't
+        // timed either. Without metrics or tracing, renders are only timed to
+        // check the render budget.
+        let started_at = if self.deterministic
+            || (cfg!(not(any(feature = "stats", feature = "tracing")))
+                && self.render_budget.is_none())
+        {
+            None
+        } else {
+            Some(self.clock.now())
+        };
+
+        let outcome = match ctx {
+            Some(ctx) => self.state.render_outcome_with(remainder, ctx),
+            None => self.state.render_outcome(remainder),
+        };
+        let outcome = match outcome {
+            Ok(outcome) => outcome,
+            // The frame was never rendered, so there's no outcome, and the
+            // game state is rendered again on the next tick.
+            Err(_) if self.render_errors_suppressed() => return Ok(None),
+            Err(err) => return Err(Error::Render(err)),
+        };
+        self.render_pending = false;
+        self.ticks_since_render = 0;
+
+        if let Some(started_at) = started_at {
+            let time = self.clock.now().checked_sub(started_at).unwrap_or_default();
+            #[cfg(feature = "stats")]
+            self.metrics.record_render_time(time);
+            #[cfg(feature = "tracing")]
+            spans::record_duration(&span, time);
+
+            if let Some(budget) = &mut self.render_budget {
+                if time > budget.budget {
+                    (budget.on_exceeded)(time);
+                }
+            }
+        }
+
+        Ok(Some(outcome))
+    }
+
+    /// Whether a failed render happens within the window after a resize in
+    /// which render errors are swallowed.
+    fn render_errors_suppressed(&self) -> bool {
+        self.resized_at.map_or(false, |resized_at| {
+            self.clock.now().checked_sub(resized_at).unwrap_or_default()
+                < self.suppress_render_errors
+        })
+    }
+
+    /// Swallow render errors for the given (clock) time after the window is
+    /// resized.
+    ///
+    /// During a storm of resizes, rendering can transiently fail, for example
+    /// because the swap chain is out of date. Within the window after the
+    /// last resize (see [`GameLoop::notify_resized`]), a failed render doesn't
+    /// fail the tick. The tick reports it didn't render, counting the error in
+    /// [`TickReport::suppressed_render_errors`], and the game state is
+    /// rendered again on the next tick. After the window, render errors fail
+    /// the tick as usual.
+    ///
+    /// Pass a zero duration to stop swallowing render errors, which is the
+    /// default.
+    pub fn set_suppress_render_errors(&mut self, window: Duration) {
+        self.suppress_render_errors = window;
+    }
+
+    /// Tell the game loop the window was just resized.
+    ///
+    /// This starts the window in which render errors are swallowed, see
+    /// [`GameLoop::set_suppress_render_errors`]. When driven by an event loop,
+    /// [`LoopEvent::Resized`] does the same.
+    pub fn notify_resized(&mut self) {
+        self.resized_at = Some(self.clock.now());
+    }
+
+    /// Set the upper bound of the remainder passed to the renderer.
+    ///
+    /// Under normal circumstances, the remainder never reaches `1.0`, but if
+    /// the accumulated time ever exceeds the update interval right before
+    /// rendering, the renderer would interpolate (or rather, extrapolate)
+    /// beyond the next update, overshooting the visual representation of the
+    /// game state. This clamp gives the renderer a safety bound. Defaults to
+    /// `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given maximum is negative, or not a number.
+    pub fn set_max_interpolation(&mut self, max_interpolation: Remainder) {
+        assert!(
+            max_interpolation >= 0.0,
+            "the maximum interpolation cannot be negative"
+        );
+
+        self.max_interpolation = max_interpolation;
+    }
+
+    /// A helper method to get the remainder stored in the game loop.
+    ///
+    /// This is meant to aid in unit testing the state of the game by inspecting
+    /// how much time is still stored as the remainder of the game loop.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the invariants of the game loop don't hold,
+    /// see [`GameLoop::validate_invariants`].
+    pub fn remainder(&self) -> Remainder {
+        self.check_invariants();
+
+        self.raw_remainder()
+    }
+
+    /// The position of the game loop in the simulation, as the sequence number
+    /// of the last update (see [`GameLoop::update_sequence`]), and the
+    /// remainder towards the next update, as passed to the renderer.
+    ///
+    /// Audio engines can use this to align the playback of samples with the
+    /// simulation: a snapshot taken after every tick (for example, stored in a
+    /// pair of atomics), tells an audio callback running on another thread
+    /// exactly where in the simulation it is.
+    ///
+    /// Like the remainder passed to the renderer, the remainder is relative to
+    /// the update before the last one in the [`RenderMode::Extrapolate`] mode,
+    /// between `1.0` and `2.0`, see [`GameLoop::set_render_mode`].
+    pub fn audio_interpolation(&self) -> (u64, Remainder) {
+        (self.update_sequence, self.current_render_remainder())
+    }
+
+    /// The remainder of the game loop as a whole percentage, from 0 up to and
+    /// including 99, for example to display on a HUD, or to log.
+    ///
+    /// Unlike [`GameLoop::remainder`], this never panics: a remainder outside
+    /// of its expected range is clamped instead. Like [`GameLoop::remainder`],
+    /// this is relative to the last update, regardless of the render mode (see
+    /// [`GameLoop::set_render_mode`]).
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn remainder_percent(&self) -> u8 {
+        (self.raw_remainder() * 100.0).max(0.0).min(99.0) as u8
+    }
+
+    /// The remainder of the game loop, rounded to the nearest multiple of
+    /// `1 / steps`.
+    ///
+    /// Pixel-art games can interpolate with this remainder to snap movement to
+    /// whole steps, avoiding sub-pixel shimmer. With four steps, the remainder
+    /// is one of 0.0, 0.25, 0.5 or 0.75: a remainder that would round up to
+    /// 1.0 stays at the last step instead.
+    ///
+    /// The remainder is relative to the last update, regardless of the render
+    /// mode. To extrapolate with it in the [`RenderMode::Extrapolate`] mode,
+    /// add `1.0`, like the game loop does for the renderer (see
+    /// [`GameLoop::set_render_mode`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `steps` is zero.
+    #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+    pub fn quantized_remainder(&self, steps: u32) -> Remainder {
+        assert!(steps > 0, "the remainder cannot be quantized to zero steps");
+
+        let steps = steps as Remainder;
+        let step = (self.raw_remainder().max(0.0) * steps)
+            .round()
+            .min(steps - 1.0);
+
+        step / steps
+    }
+
+    /// Validate the invariants of the game loop.
+    ///
+    /// In between ticks, the remainder of the game loop is expected to be 0.0
+    /// or higher, and lower than 1.0, which no longer holds if more time is
+    /// added manually (see [`GameLoop::add_accumulated_time`]) than the next
+    /// tick drains, or if a tick is capped before it drains all accumulated
+    /// time (see [`GameLoop::set_max_updates_per_tick`]).
+    pub fn validate_invariants(&self) -> Result<(), InvariantViolation> {
+        if self.update_interval == Duration::default() {
+            return Err(InvariantViolation::ZeroUpdateInterval);
+        }
+
+        if self.accumulated_time >= self.update_interval {
+            return Err(InvariantViolation::RemainderOutOfRange);
+        }
+
+        Ok(())
+    }
+
+    /// Panic if any of the invariants of the game loop doesn't hold, see
+    /// [`GameLoop::validate_invariants`].
+    ///
+    /// This is the single place the game loop checks its own invariants, which
+    /// only happens in debug builds.
+    fn check_invariants(&self) {
+        if cfg!(debug_assertions) {
+            if let Err(violation) = self.validate_invariants() {
+                panic!("game loop invariant violated: {}", violation);
+            }
+        }
+    }
+
+    /// The remainder stored in the game loop, without validating it falls
+    /// within the expected bounds.
+    ///
+    /// Both durations are divided as whole nanoseconds, so the precision of the
+    /// remainder only depends on the [`Remainder`] type. Even for durations of
+    /// many hours (such as after [`GameLoop::skip_time`]), the remainder stays
+    /// finite, and accurate to the precision of an `f32` (or `f64`).
+    #[allow(clippy::cast_precision_loss)]
+    fn raw_remainder(&self) -> Remainder {
+        (self.accumulated_time.as_nanos() as Remainder)
+            / (self.update_interval.as_nanos() as Remainder)
+    }
+
+    /// The phase the game loop is currently in.
+    ///
+    /// See [`Phase`] for more details.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// The interval between two updates of the game state.
+    pub fn update_interval(&self) -> Duration {
+        self.update_interval
+    }
+
+    /// Whether the update interval doesn't divide a second evenly.
+    ///
+    /// The update interval is truncated to whole nanoseconds, so at update
+    /// rates that don't divide a billion, such as 3 updates per second, every
+    /// second of simulated time runs slightly more updates than configured,
+    /// drifting away from real time. A configuration UI can use this to flag
+    /// such rates.
+    pub fn interval_has_remainder(&self) -> bool {
+        u128::from(NANOSECONDS_PER_SECOND)
+            .checked_rem(self.update_interval.as_nanos())
+            .map_or(true, |remainder| remainder != 0)
+    }
+
+    /// Temporarily raise the update rate to the given amount of updates per
+    /// second, for the given amount of ticks.
+    ///
+    /// Once the ticks completed, the update interval reverts to the one used
+    /// before the boost. Boosting while a boost is still active replaces the
+    /// active boost, but still reverts to the interval used before the first
+    /// boost. This suits features such as fast-forwarding to the next event in
+    /// the game.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given rate is zero, or more than a billion.
+    pub fn boost_update_rate(&mut self, rate: u32, ticks: usize) {
+        let restore_interval = self
+            .boost
+            .map_or(self.update_interval, |boost| boost.restore_interval);
+
+        let interval = interval_from_rate(rate);
+        assert!(
+            interval > Duration::default(),
+            "the game loop can't update more than a billion times per second"
+        );
+        self.update_interval = interval;
+        self.boost = Some(Boost {
+            remaining_ticks: ticks,
+            restore_interval,
+        });
+        self.end_boost_if_done();
+    }
+
+    /// Apply the update rate requested by the game state, if any, see
+    /// [`Updater::desired_update_rate`].
+    ///
+    /// While the update rate is boosted, the requested rate is applied once the
+    /// boost ends. Rates resulting in a zero update interval are ignored, as
+    /// draining the accumulated time would never end.
+    fn apply_desired_update_rate(&mut self) {
+        let interval = match self.state.desired_update_rate() {
+            Some(rate) if rate > 0 && rate <= NANOSECONDS_PER_SECOND => interval_from_rate(rate),
+            Some(_) | None => return,
+        };
+
+        match &mut self.boost {
+            Some(boost) => boost.restore_interval = interval,
+            None => self.update_interval = interval,
+        }
+    }
+
+    /// Count down the active boost after a completed tick.
+    fn complete_boosted_tick(&mut self) {
+        if let Some(boost) = &mut self.boost {
+            boost.remaining_ticks = boost.remaining_ticks.saturating_sub(1);
+        }
+        self.end_boost_if_done();
+    }
+
+    /// Restore the update interval if the active boost has no ticks left.
+    fn end_boost_if_done(&mut self) {
+        if let Some(boost) = self.boost {
+            if boost.remaining_ticks == 0 {
+                self.update_interval = boost.restore_interval;
+                self.boost = None;
+            }
+        }
+    }
+
+    /// Label the game loop, to tell it apart from other game loops.
+    ///
+    /// In a game running multiple loops, such as a simulation loop and a UI
+    /// loop, the label is included when displaying the game loop, and in the
+    /// reports of its ticks (see [`TickReport::label`]), so it's clear which
+    /// loop a metric came from.
+    pub fn set_label<L>(&mut self, label: L)
+    where
+        L: Into<String>,
+    {
+        self.label = Some(Arc::from(label.into()));
+    }
+
+    /// The label of the game loop, if any, see [`GameLoop::set_label`].
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(AsRef::as_ref)
+    }
+
+    /// A helper method to inspect the game state.
+    ///
+    /// This is meant to aid in unit testing the state of the game by allowing
+    /// inspection (or mutation) of the game state after performing a game tick.
+    pub fn state(&mut self) -> &mut T {
+        &mut self.state
+    }
+
+    /// The time accumulated since the last update, not yet drained by an
+    /// update of the game state.
+    pub fn accumulated_time(&self) -> Duration {
+        self.accumulated_time
+    }
+
+    /// Multiply the accumulated time by the given factor, once.
+    ///
+    /// Unlike changing the update rate, this only affects the time already
+    /// accumulated, not the time accumulated by future ticks. For example, a
+    /// factor of `0.0` discards the accumulated time, skipping the updates that
+    /// were due, while `2.0` doubles the updates due on the next tick.
+    ///
+    /// The scaled time saturates at the longest time a `Duration` built from
+    /// nanoseconds can hold.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given factor is negative, infinite, or not a number.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn scale_accumulated_time(&mut self, factor: f32) {
+        assert!(factor >= 0.0, "the scale factor cannot be negative");
+        assert!(factor.is_finite(), "the scale factor must be finite");
+
+        // Casting a float beyond the range of the integer is undefined, and
+        // `u64::max_value()` isn't representable as a float, so saturate
+        // before casting.
+        let nanos = self.accumulated_time.as_nanos() as f64 * f64::from(factor);
+        self.accumulated_time = if nanos < u64::max_value() as f64 {
+            Duration::from_nanos(nanos as u64)
+        } else {
+            Duration::from_nanos(u64::max_value())
+        };
+    }
+
+    /// A helper method to increase the accumulated time by a fixed amount.
+    ///
+    /// This is meant to aid in unit testing the state of the game by forcing
+    /// the updater to run a fixed amount of times when triggering another game
+    /// tick.
+    pub fn add_accumulated_time(&mut self, add: Duration) {
+        self.accumulated_time += add;
+    }
+}
+
+/// Cloning a game loop clones the game state, along with the timing state of
+/// the loop, such as its accumulated time, allowing the clone to run forward
+/// without affecting the original, for example for AI rollouts, or network
+/// prediction.
+///
+/// A few things aren't shared with the clone:
+///
+/// * The clone doesn't remember the start of the last tick, so its first tick
+///   doesn't accumulate any time, just like the first tick of a new loop.
+/// * The clone gets its own [`StopHandle`], so stopping one loop doesn't stop
+///   the other.
+/// * The clone doesn't invoke the callbacks set through
+///   [`GameLoop::set_on_update_boundary`], [`GameLoop::set_render_budget`],
+///   [`GameLoop::set_on_catch_up_start`], [`GameLoop::set_on_catch_up_end`],
+///   and [`GameLoop::set_on_second`], and doesn't write to the writer set
+///   through [`GameLoop::set_trace_writer`].
+/// * With the [`ClockSource::Manual`] clock source, the clone gets its own
+///   clock, set to the time of the original clock, so advancing one loop
+///   doesn't advance the other.
+impl<T> Clone for GameLoop<T>
+where
+    T: Updater + Renderer + Clone,
+{
+    fn clone(&self) -> Self {
+        let manual_clock = self.manual_clock.as_ref().map(|original| {
+            let clock = ManualClock::new();
+            clock.set(original.now());
+            clock
+        });
+        let clock: Arc<dyn Clock> = match &manual_clock {
+            Some(manual_clock) => Arc::new(manual_clock.clone()),
+            None => Arc::clone(&self.clock),
+        };
+
+        Self {
+            state: self.state.clone(),
+            update_interval: self.update_interval,
+            previous_tick: None,
+            accumulated_time: self.accumulated_time,
+            render_on_exit: self.render_on_exit,
+            shutdown_policy: self.shutdown_policy,
+            backward_time_policy: self.backward_time_policy,
+            render_pending: self.render_pending,
+            stop_handle: StopHandle::default(),
+            phase: self.phase,
+            clock,
+            manual_clock,
+            render_clock: self.render_clock.as_ref().map(Arc::clone),
+            render_clock_read_at: None,
+            max_interpolation: self.max_interpolation,
+            deterministic: self.deterministic,
+            paused: self.paused,
+            suspended: self.suspended,
+            skip_next_frame_time: self.skip_next_frame_time,
+            render_enabled: self.render_enabled,
+            render_every: self.render_every,
+            ticks_since_render: self.ticks_since_render,
+            min_render_interval: self.min_render_interval,
+            render_time: self.render_time,
+            suppress_render_errors: self.suppress_render_errors,
+            resized_at: self.resized_at,
+            update_sequence: self.update_sequence,
+            simulation_time: self.simulation_time,
+            auto_pause_after: self.auto_pause_after,
+            idle_time: self.idle_time,
+            auto_paused: self.auto_paused,
+            label: self.label.clone(),
+            cpu_budget: self.cpu_budget,
+            min_frame_time: self.min_frame_time,
+            max_frame_time: self.max_frame_time,
+            timestep_mode: self.timestep_mode,
+            render_mode: self.render_mode,
+            forced_frame_time: None,
+            tick_at_reference: self.tick_at_reference,
+            on_update_boundary: None,
+            on_catch_up_start: None,
+            on_second: None,
+            second_started_at: None,
+            on_catch_up_end: None,
+            catching_up: self.catching_up,
+            catch_up_streak: self.catch_up_streak,
+            render_budget: None,
+            trace_writer: None,
+            first_tick_at: self.first_tick_at,
+            update_substeps: self.update_substeps,
+            #[cfg(feature = "stats")]
+            metrics: self.metrics.clone(),
+            max_updates_per_tick: self.max_updates_per_tick,
+            max_updates_per_real_second: self.max_updates_per_real_second,
+            max_accumulated_intervals: self.max_accumulated_intervals,
+            recent_updates: self.recent_updates.clone(),
+            boost: self.boost,
+        }
+    }
+}
+
+impl<T> fmt::Display for GameLoop<T>
+where
+    T: Updater + Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.label {
+            Some(label) => write!(f, "game loop `{}`", label),
+            None => f.write_str("game loop"),
+        }
+    }
+}
+
+/// The interval between two updates, for the given amount of updates per
+/// second.
+///
+/// The interval is zero for more than a billion updates per second, which
+/// callers have to reject, as draining the accumulated time would never end.
+///
+/// # Panics
+///
+/// Panics if the amount of updates per second is zero.
+pub(crate) fn interval_from_rate(updates_per_second: u32) -> Duration {
+    assert!(
+        updates_per_second > 0,
+        "the game loop needs at least one update per second"
+    );
+
+    Duration::from_nanos(u64::from(NANOSECONDS_PER_SECOND / updates_per_second))
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, Clone)]
+    struct State {
+        update: usize,
+        render: usize,
+        remainder: Remainder,
+        steps: Vec<Duration>,
+        pause: usize,
+        resume: usize,
+        stop_after_update: Option<StopHandle>,
+        corrected: bool,
+        jumped: bool,
+        desired_rate: Option<u32>,
+        request_rate_on_update: Option<u32>,
+        extra_updates_requested: usize,
+        inactive: bool,
+        skip_present: bool,
+        work: Option<(ManualClock, Duration)>,
+        render_work: Option<(ManualClock, Duration)>,
+        render_failures: usize,
+    }
+
+    /// The context passed to the game state in tests.
+    #[derive(Debug)]
+    struct Input {
+        jump: bool,
+    }
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.update += 1;
+            if let Some(handle) = &self.stop_after_update {
+                handle.stop();
+            }
+            if let Some(rate) = self.request_rate_on_update {
+                self.desired_rate = Some(rate);
+            }
+            if let Some((clock, duration)) = &self.work {
+                clock.advance(*duration);
+            }
+            Ok(())
+        }
+
+        fn update_step(&mut self, step: Duration) -> Result<(), Self::Error> {
+            self.steps.push(step);
+            self.update()
+        }
+
+        fn update_substep(
+            &mut self,
+            step: Duration,
+            _substep: u32,
+            _substeps: u32,
+        ) -> Result<(), Self::Error> {
+            self.update_step(step)
+        }
+
+        fn on_pause(&mut self) {
+            self.pause += 1;
+        }
+
+        fn on_resume(&mut self) {
+            self.resume += 1;
+        }
+
+        fn interpolation_corrected(&self) -> bool {
+            self.corrected
+        }
+
+        fn desired_update_rate(&self) -> Option<u32> {
+            self.desired_rate
+        }
+
+        fn is_active(&self) -> bool {
+            !self.inactive
+        }
+
+        fn update_with<C: Any>(&mut self, step: Duration, ctx: &C) -> Result<(), Self::Error> {
+            let ctx: &dyn Any = ctx;
+            if let Some(input) = ctx.downcast_ref::<Input>() {
+                self.jumped |= input.jump;
+            }
+            self.update_step(step)
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, remainder: Remainder) -> Result<(), Self::Error> {
+            if self.render_failures > 0 {
+                self.render_failures -= 1;
+                return Err(std::io::Error::new(std::io::ErrorKind::Other, "lost"));
+            }
+
+            self.render += 1;
+            self.remainder = remainder;
+            if let Some((clock, duration)) = &self.render_work {
+                clock.advance(*duration);
+            }
+            Ok(())
+        }
+
+        fn render_outcome(&mut self, remainder: Remainder) -> Result<RenderOutcome, Self::Error> {
+            self.render(remainder)?;
+
+            let request_extra_update = self.extra_updates_requested > 0;
+            self.extra_updates_requested = self.extra_updates_requested.saturating_sub(1);
+
+            Ok(RenderOutcome {
+                presented: !self.skip_present,
+                request_extra_update,
+            })
+        }
+
+        fn render_outcome_with<C: Any>(
+            &mut self,
+            remainder: Remainder,
+            _ctx: &C,
+        ) -> Result<RenderOutcome, Self::Error> {
+            self.render_outcome(remainder)
+        }
+    }
+
+    #[test]
+    fn test_game_loop_state() {
+        let mut game_loop = GameLoop::new(State {
+            update: 1,
+            render: 2,
+            ..State::default()
+        });
+
+        assert_eq!(game_loop.state().update, 1);
+        assert_eq!(game_loop.state().render, 2);
+    }
+
+    #[test]
+    fn test_game_loop_tick_drains_accumulated_time() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        // we run at 100 FPS, so update the game state every 10ms
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 1);
+
+        // At the last tick, the updater ran once, and drained all accumulated
+        // time. We add 6 more milliseconds, bringing the total to 6, so no new
+        // update is triggered.
+        game_loop.add_accumulated_time(Duration::from_millis(6));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 1);
+
+        // We still have 6 milliseconds accumulated, by adding 16 more, we end
+        // up with 22, so the updater runs twice, leaving 2 accumulated
+        // milliseconds.
+        game_loop.add_accumulated_time(Duration::from_millis(16));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 3);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_game_loop_remainder() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.add_accumulated_time(Duration::from_millis(9));
+        assert_eq!(game_loop.remainder(), 0.9);
+    }
+
+    #[test]
+    fn test_game_loop_invalid_remainder() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+
+        // The remainder has to be 0.0 or higher, and lower than 1.0 to be
+        // valid. The only way this invalid state can be triggered is if the
+        // `add_accumulated_time` is used to manually add 10 or more
+        // milliseconds, without using `tick` to consume that accumulated time
+        // down to below 10.
+        assert_eq!(
+            game_loop.validate_invariants(),
+            Err(InvariantViolation::RemainderOutOfRange)
+        );
+
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.validate_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_game_loop_tick_runs_renderer() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().render, 1);
+    }
+
+    #[test]
+    fn test_game_loop_run_renders_on_exit() {
+        let mut game_loop = GameLoopBuilder::new()
+            .render_on_exit(true)
+            .build(State::default())
+            .unwrap();
+        game_loop.state().stop_after_update = Some(game_loop.stop_handle());
+
+        // Three updates are due, but the first one requests the loop to stop,
+        // abandoning the tick before it gets to render.
+        game_loop.add_accumulated_time(Duration::from_millis(30));
+        game_loop.run().unwrap();
+
+        assert_eq!(game_loop.state().update, 1);
+        assert_eq!(game_loop.state().render, 1);
+    }
+
+    #[test]
+    fn test_game_loop_run_without_render_on_exit() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.state().stop_after_update = Some(game_loop.stop_handle());
+
+        game_loop.add_accumulated_time(Duration::from_millis(30));
+        game_loop.run().unwrap();
+
+        assert_eq!(game_loop.state().update, 1);
+        assert_eq!(game_loop.state().render, 0);
+    }
+
+    #[test]
+    fn test_game_loop_phase_is_idle_after_tick() {
+        let mut game_loop = GameLoop::new(State::default());
+        assert_eq!(game_loop.phase(), Phase::Idle);
+
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.phase(), Phase::Idle);
+    }
+
+    #[test]
+    fn test_game_loop_uses_injected_clock() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+
+        game_loop.tick().unwrap();
+        clock.advance(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().update, 2);
+    }
+
+    #[test]
+    fn test_game_loop_fast_forward() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.fast_forward(1000).unwrap();
+
+        assert_eq!(game_loop.state().update, 1000);
+        assert_eq!(game_loop.state().render, 0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_game_loop_max_interpolation() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.set_max_interpolation(0.5);
+
+        game_loop.add_accumulated_time(Duration::from_millis(9));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().remainder, 0.5);
+
+        // A capped tick leaves more than an update interval in the
+        // accumulator, which the renderer never gets to see.
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.set_max_updates_per_tick(Some(1));
+
+        game_loop.add_accumulated_time(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().update, 1);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_millis(15));
+        assert_eq!(game_loop.state().remainder, 1.0);
+    }
+
+    #[test]
+    fn test_game_loop_deterministic() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        for _ in 0..5 {
+            game_loop.tick().unwrap();
+        }
+
+        assert_eq!(game_loop.state().update, 5);
+        assert_eq!(game_loop.state().render, 5);
+    }
+
+    #[test]
+    fn test_game_loop_pause_and_resume() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.pause();
+        game_loop.pause();
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().pause, 1);
+        assert_eq!(game_loop.state().update, 0);
+        assert_eq!(game_loop.state().render, 1);
+
+        game_loop.resume();
+        game_loop.resume();
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().resume, 1);
+        assert_eq!(game_loop.state().update, 1);
+    }
+
+    #[test]
+    fn test_game_loop_tick_report() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.set_render_enabled(false);
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+        let report = game_loop.tick_report().unwrap();
+
+        assert_eq!(report.updates, 2);
+        assert!(!report.rendered);
+
+        game_loop.set_render_enabled(true);
+        let report = game_loop.tick_report().unwrap();
+
+        assert!(report.rendered);
+        assert_eq!(game_loop.state().render, 1);
+    }
+
+    #[test]
+    fn test_game_loop_update_rate_below_frame_rate() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .updates_per_second(1)
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+
+        game_loop.tick().unwrap();
+
+        // Render one second worth of frames at 60 FPS.
+        let mut remainders = vec![];
+        for frame in 1..=60 {
+            clock.set(Duration::from_secs(1) * frame / 60);
+            game_loop.tick().unwrap();
+            remainders.push(game_loop.state().remainder);
+        }
+
+        // The single update only runs on the last frame, until then, the
+        // remainder steadily grows towards 1.0, after which it starts over.
+        assert_eq!(game_loop.state().update, 1);
+        assert!(remainders[..59].windows(2).all(|w| w[0] < w[1]));
+        assert!(remainders[58] > 0.98);
+        assert!(remainders[59] < 1e-6);
+    }
+
+    #[test]
+    fn test_game_loop_real_elapsed() {
+        let clock = ManualClock::new();
+        clock.advance(Duration::from_secs(5));
+
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        assert_eq!(game_loop.real_elapsed(), Duration::default());
+
+        game_loop.tick().unwrap();
+        clock.advance(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+        clock.advance(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+        clock.advance(Duration::from_millis(5));
+
+        assert_eq!(game_loop.real_elapsed(), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_game_loop_update_substeps() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.set_update_substeps(2);
+
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(game_loop.state().steps, vec![Duration::from_millis(5); 2]);
+    }
+
+    #[test]
+    fn test_game_loop_update_substeps_by_default() {
+        #[derive(Debug, Default)]
+        struct Stepper {
+            steps: Vec<Duration>,
+        }
+
+        impl Updater for Stepper {
+            type Error = std::io::Error;
+
+            fn update(&mut self) -> Result<(), Self::Error> {
+                Ok(())
+            }
+
+            fn update_step(&mut self, step: Duration) -> Result<(), Self::Error> {
+                self.steps.push(step);
+                Ok(())
+            }
+        }
+
+        impl Renderer for Stepper {
+            type Error = std::io::Error;
+
+            fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        let mut game_loop = GameLoop::new(Stepper::default());
+        game_loop.set_update_substeps(4);
+
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().steps, vec![Duration::from_millis(10); 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_drain_metrics() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        for _ in 0..3 {
+            game_loop.tick().unwrap();
+        }
+
+        let batch = game_loop.drain_metrics();
+        assert_eq!(batch.len(), 3);
+        assert!(batch.into_iter().all(|report| report.updates == 1));
+
+        assert!(game_loop.drain_metrics().is_empty());
+    }
+
+    #[test]
+    fn test_game_loop_catch_up() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+
+        game_loop.tick().unwrap();
+        clock.advance(Duration::from_millis(55));
+
+        assert_eq!(game_loop.catch_up().unwrap(), 5);
+        assert_eq!(game_loop.state().update, 5);
+        assert_eq!(game_loop.state().render, 1);
+
+        // With a cap, the remaining time is carried over to the next call.
+        game_loop.set_max_updates_per_tick(Some(2));
+        clock.advance(Duration::from_millis(25));
+
+        assert_eq!(game_loop.catch_up().unwrap(), 2);
+        assert_eq!(game_loop.catch_up().unwrap(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "high-precision")]
+    fn test_game_loop_high_precision_remainder() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.add_accumulated_time(Duration::from_nanos(1));
+        game_loop.tick().unwrap();
+
+        // The remainder is too small to be represented as an `f32` relative to
+        // the interval, but an `f64` keeps track of it exactly.
+        let remainder: f64 = game_loop.state().remainder;
+        assert!((remainder - 1e-7).abs() < std::f64::EPSILON);
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_stutter_count() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.stutter_count(), 0);
+
+        // Every update reporting a correction counts as a stutter.
+        game_loop.state().corrected = true;
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.stutter_count(), 2);
+
+        // The count survives draining the metrics.
+        let _ = game_loop.drain_metrics();
+        assert_eq!(game_loop.stutter_count(), 2);
+    }
+
+    #[test]
+    fn test_game_loop_manual_clock_source() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+
+        // Without supplying a delta, no time passes.
+        game_loop.tick().unwrap();
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 0);
+
+        game_loop
+            .tick_with_delta(Duration::from_millis(20))
+            .unwrap();
+        assert_eq!(game_loop.state().update, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_sustainable_update_rate() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+
+        game_loop.tick().unwrap();
+        for _ in 0..7 {
+            clock.advance(Duration::from_millis(10));
+            game_loop.tick().unwrap();
+        }
+
+        // Not enough frame times were measured yet.
+        assert_eq!(game_loop.sustainable_update_rate(), None);
+
+        clock.advance(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.sustainable_update_rate(), Some(100));
+    }
+
+    #[test]
+    fn test_game_loop_boost_update_rate() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+        let interval = game_loop.update_interval();
+
+        game_loop.boost_update_rate(1000, 3);
+        assert_eq!(game_loop.update_interval(), Duration::from_millis(1));
+
+        for _ in 0..2 {
+            game_loop.tick().unwrap();
+            assert_eq!(game_loop.update_interval(), Duration::from_millis(1));
+        }
+
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.update_interval(), interval);
+    }
+
+    #[test]
+    fn test_game_loop_tick_reason() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.add_accumulated_time(Duration::from_millis(5));
+        let report = game_loop.tick_report().unwrap();
+        assert_eq!(
+            report.reason,
+            TickReason::RenderedOnly(Duration::from_millis(5))
+        );
+
+        game_loop.add_accumulated_time(Duration::from_millis(5));
+        let report = game_loop.tick_report().unwrap();
+        assert_eq!(report.reason, TickReason::UpdatedAndRendered);
+
+        game_loop.state().skip_present = true;
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        let report = game_loop.tick_report().unwrap();
+        assert_eq!(report.reason, TickReason::UpdatedOnly);
+
+        game_loop.state().skip_present = false;
+        game_loop.set_render_enabled(false);
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        let report = game_loop.tick_report().unwrap();
+        assert_eq!(report.reason, TickReason::UpdatedOnly);
+        game_loop.set_render_enabled(true);
+
+        game_loop.set_max_updates_per_tick(Some(1));
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+        let report = game_loop.tick_report().unwrap();
+        assert_eq!(report.reason, TickReason::CappedCatchUp);
+
+        game_loop.pause();
+        let report = game_loop.tick_report().unwrap();
+        assert_eq!(report.reason, TickReason::Paused);
+    }
+
+    #[test]
+    fn test_game_loop_tick_with_context() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        game_loop.tick_with_context(&Input { jump: false }).unwrap();
+        assert!(!game_loop.state().jumped);
+
+        game_loop.tick_with_context(&Input { jump: true }).unwrap();
+        assert!(game_loop.state().jumped);
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(game_loop.state().render, 2);
+    }
+
+    #[test]
+    fn test_game_loop_tick_with_context_substeps() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.set_update_substeps(2);
+
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+        game_loop.tick_with_context(&Input { jump: true }).unwrap();
+
+        // Updates with a context receive the step of the whole update.
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(game_loop.state().steps, vec![Duration::from_millis(10); 2]);
+    }
+
+    #[test]
+    fn test_game_loop_scale_accumulated_time() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        game_loop.add_accumulated_time(Duration::from_millis(8));
+        game_loop.scale_accumulated_time(2.0);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_millis(16));
+
+        game_loop.scale_accumulated_time(0.0);
+        assert_eq!(game_loop.accumulated_time(), Duration::default());
+
+        game_loop.add_accumulated_time(Duration::from_secs(1));
+        game_loop.scale_accumulated_time(std::f32::MAX);
+        assert_eq!(
+            game_loop.accumulated_time(),
+            Duration::from_nanos(u64::max_value())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the scale factor must be finite")]
+    fn test_game_loop_scale_accumulated_time_infinite() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.scale_accumulated_time(std::f32::INFINITY);
+    }
+
+    #[test]
+    fn test_game_loop_render_every() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        game_loop.set_render_every(3);
+        for _ in 0..9 {
+            game_loop.tick().unwrap();
+        }
+
+        assert_eq!(game_loop.state().update, 9);
+        assert_eq!(game_loop.state().render, 3);
+    }
+
+    #[test]
+    fn test_game_loop_ticks_since_render() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        game_loop.set_render_every(4);
+        assert_eq!(game_loop.ticks_since_render(), 0);
+
+        for expected in &[1, 2, 3, 0, 1] {
+            game_loop.tick().unwrap();
+            assert_eq!(game_loop.ticks_since_render(), *expected);
+        }
+
+        game_loop.render_now().unwrap();
+        assert_eq!(game_loop.ticks_since_render(), 0);
+    }
+
+    #[test]
+    fn test_game_loop_update_sequence() {
+        let replay = || {
+            let mut game_loop = GameLoopBuilder::new()
+                .clock_source(ClockSource::Manual)
+                .build(State::default())
+                .unwrap();
+
+            for delta in &[0, 16, 4, 33, 7, 16] {
+                game_loop
+                    .tick_with_delta(Duration::from_millis(*delta))
+                    .unwrap();
+            }
+
+            game_loop.update_sequence()
+        };
+
+        assert_eq!(replay(), 7);
+        assert_eq!(replay(), replay());
+    }
+
+    #[test]
+    fn test_game_loop_desired_update_rate() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        // The first update requests half the update rate, but the tick keeps
+        // draining the accumulated time in steps of the original interval.
+        game_loop.state().request_rate_on_update = Some(50);
+        game_loop.add_accumulated_time(Duration::from_millis(30));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().update, 3);
+        assert_eq!(game_loop.update_interval(), Duration::from_millis(10));
+
+        // The next tick applies the new rate, before draining.
+        game_loop.add_accumulated_time(Duration::from_millis(30));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().update, 4);
+        assert_eq!(game_loop.update_interval(), Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_game_loop_invalid_desired_update_rate() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        for rate in &[0, u32::max_value()] {
+            game_loop.state().desired_rate = Some(*rate);
+            game_loop.add_accumulated_time(Duration::from_millis(10));
+            game_loop.tick().unwrap();
+
+            assert_eq!(game_loop.update_interval(), Duration::from_millis(10));
+        }
+        assert_eq!(game_loop.state().update, 2);
+    }
+
+    #[test]
+    fn test_game_loop_label() {
+        let mut game_loop = GameLoop::new(State::default());
+        assert_eq!(game_loop.to_string(), "game loop");
+        assert_eq!(game_loop.tick_report().unwrap().label, None);
+
+        game_loop.set_label("simulation");
+        assert_eq!(game_loop.label(), Some("simulation"));
+        assert_eq!(game_loop.to_string(), "game loop `simulation`");
+
+        let report = game_loop.tick_report().unwrap();
+        assert_eq!(report.label.as_ref().map(AsRef::as_ref), Some("simulation"));
+    }
+
+    #[test]
+    fn test_game_loop_flush() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        // Nothing to flush before the game state was updated.
+        game_loop.flush().unwrap();
+        assert_eq!(game_loop.state().render, 0);
+
+        game_loop.set_render_every(2);
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().render, 0);
+
+        game_loop.flush().unwrap();
+        game_loop.flush().unwrap();
+        assert_eq!(game_loop.state().render, 1);
+    }
+
+    #[test]
+    fn test_game_loop_render_outcome() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        let report = game_loop.tick_report().unwrap();
+        assert!(report.rendered);
+        assert!(report.presented);
+
+        game_loop.state().skip_present = true;
+        let report = game_loop.tick_report().unwrap();
+        assert!(report.rendered);
+        assert!(!report.presented);
+    }
+
+    #[test]
+    fn test_game_loop_render_outcome_with_context() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        // The outcome of a render with a context is honoured as well.
+        game_loop.state().extra_updates_requested = 1;
+        game_loop.tick_with_context(&Input { jump: false }).unwrap();
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(game_loop.state().render, 2);
+    }
+
+    #[test]
+    fn test_game_loop_clone() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        game_loop.tick().unwrap();
+        game_loop.add_accumulated_time(Duration::from_millis(25));
+
+        let mut clone = game_loop.clone();
+        clone.tick_with_delta(Duration::from_millis(50)).unwrap();
+        clone.tick_with_delta(Duration::from_millis(50)).unwrap();
+
+        assert_eq!(clone.state().update, 7);
+        assert_eq!(game_loop.state().update, 0);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_millis(25));
+        assert_eq!(game_loop.real_elapsed(), Duration::default());
+    }
+
+    #[test]
+    fn test_game_loop_cpu_budget() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.state().stop_after_update = Some(game_loop.stop_handle());
+
+        // The update keeps the CPU busy for 10 milliseconds, so with a budget
+        // of half the time, the loop sleeps another 10 milliseconds.
+        game_loop.state().work = Some((clock, Duration::from_millis(10)));
+        game_loop.set_cpu_budget(Some(0.5));
+        game_loop.add_accumulated_time(Duration::from_millis(10));
+        game_loop.run().unwrap();
+
+        assert_eq!(game_loop.state().update, 1);
+        assert_eq!(game_loop.real_elapsed(), Duration::from_millis(20));
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_effective_update_rate() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        assert!(game_loop.effective_update_rate() < std::f32::EPSILON);
+
+        game_loop.tick().unwrap();
+        for _ in 0..10 {
+            clock.advance(Duration::from_millis(10));
+            game_loop.tick().unwrap();
+        }
+
+        assert!((game_loop.effective_update_rate() - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_game_loop_shutdown_policy_drain_updates() {
+        let mut game_loop = GameLoopBuilder::new()
+            .shutdown_policy(ShutdownPolicy::DrainUpdates)
+            .build(State::default())
+            .unwrap();
+
+        game_loop.add_accumulated_time(Duration::from_millis(25));
+        game_loop.stop_handle().stop();
+        game_loop.run().unwrap();
+
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(game_loop.state().render, 1);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_game_loop_interval_has_remainder() {
+        let game_loop = GameLoopBuilder::new()
+            .updates_per_second(100)
+            .build(State::default())
+            .unwrap();
+        assert!(!game_loop.interval_has_remainder());
+
+        let game_loop = GameLoopBuilder::new()
+            .updates_per_second(3)
+            .build(State::default())
+            .unwrap();
+        assert!(game_loop.interval_has_remainder());
+    }
+
+    #[test]
+    fn test_game_loop_run_until() {
+        let mut game_loop = GameLoop::deterministic(State::default());
+
+        assert!(game_loop
+            .run_until(Some(100), |state| state.update > 5)
+            .unwrap());
+        assert_eq!(game_loop.state().update, 6);
+
+        // The predicate never holds, so the loop gives up.
+        assert!(!game_loop
+            .run_until(Some(10), |state| state.update > 100)
+            .unwrap());
+        assert_eq!(game_loop.state().update, 16);
+    }
+
+    #[test]
+    fn test_game_loop_on_update_boundary() {
+        use std::sync::Mutex;
+
+        let boundaries = Arc::new(Mutex::new(vec![]));
+        let mut game_loop = GameLoop::new(State::default());
+
+        let recorded = Arc::clone(&boundaries);
+        game_loop.set_on_update_boundary(Box::new(move |index| {
+            recorded.lock().unwrap().push(index);
+        }));
+        game_loop.add_accumulated_time(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+
+        assert_eq!(*boundaries.lock().unwrap(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_game_loop_timestep_mode() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.tick().unwrap();
+
+        clock.advance(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 2);
+
+        // The variable mode discards the 5 leftover milliseconds, and runs a
+        // single update over all the time passed since the last tick.
+        game_loop.set_timestep_mode(TimestepMode::Variable);
+        assert_eq!(game_loop.accumulated_time(), Duration::default());
+
+        clock.advance(Duration::from_millis(37));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 3);
+        assert_eq!(game_loop.state().steps[2], Duration::from_millis(37));
+        assert_eq!(game_loop.accumulated_time(), Duration::default());
+
+        game_loop.set_timestep_mode(TimestepMode::Fixed);
+        clock.advance(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 5);
+        assert_eq!(game_loop.state().steps[4], Duration::from_millis(10));
+    }
+
+    /// A writer appending to a buffer shared with the test.
+    #[derive(Debug, Clone, Default)]
+    struct SharedBuffer(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_game_loop_trace_writer() {
+        let buffer = SharedBuffer::default();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        game_loop.set_trace_writer(Box::new(buffer.clone()));
+
+        game_loop.add_accumulated_time(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+        game_loop.tick().unwrap();
+        game_loop.pause();
+        game_loop.tick().unwrap();
+
+        let trace = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            trace.lines().collect::<Vec<_>>(),
+            vec![
+                "2,true,true,updated_and_rendered,0",
+                "0,true,true,rendered_only,5000000",
+                "0,true,true,paused,0",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_reset_metrics() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.tick().unwrap();
+
+        // A one second stall, as if loading a level.
+        clock.advance(Duration::from_secs(1));
+        game_loop.tick().unwrap();
+        for _ in 0..7 {
+            clock.advance(Duration::from_millis(10));
+            game_loop.tick().unwrap();
+        }
+        assert!(game_loop.effective_update_rate() > 0.0);
+
+        let accumulated_time = game_loop.accumulated_time();
+        let update_sequence = game_loop.update_sequence();
+        game_loop.reset_metrics();
+
+        assert!(game_loop.effective_update_rate().abs() < std::f32::EPSILON);
+        assert_eq!(game_loop.sustainable_update_rate(), None);
+        assert_eq!(game_loop.accumulated_time(), accumulated_time);
+        assert_eq!(game_loop.update_sequence(), update_sequence);
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_peak_single_update_time() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        assert_eq!(game_loop.peak_single_update_time(), Duration::default());
+
+        for work in &[2, 40, 5] {
+            game_loop.state().work = Some((clock.clone(), Duration::from_millis(*work)));
+            game_loop.add_accumulated_time(Duration::from_millis(10));
+            game_loop.tick().unwrap();
+        }
+
+        assert_eq!(
+            game_loop.peak_single_update_time(),
+            Duration::from_millis(40)
+        );
+    }
+
+    #[test]
+    fn test_game_loop_with_shared_clock() {
+        let mut primary = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        let mut secondary = GameLoop::with_shared_clock(State::default(), &primary);
+        primary.tick().unwrap();
+        secondary.tick().unwrap();
+
+        primary.advance(Duration::from_millis(25));
+        primary.tick().unwrap();
+        secondary.tick().unwrap();
+        assert_eq!(primary.state().update, 2);
+        assert_eq!(secondary.state().update, 2);
+
+        secondary.advance(Duration::from_millis(10));
+        primary.tick().unwrap();
+        secondary.tick().unwrap();
+        assert_eq!(primary.state().update, 3);
+        assert_eq!(secondary.state().update, 3);
+        assert_eq!(primary.accumulated_time(), secondary.accumulated_time());
+    }
+
+    #[test]
+    fn test_game_loop_advance_frame() {
+        let mut game_loop = GameLoop::new(State::default());
+
+        // 60 frames of 16.67 milliseconds add up to 100 updates of 10
+        // milliseconds, no matter how fast the frames are produced.
+        let mut updates = vec![];
+        for _ in 0..60 {
+            let before = game_loop.state().update;
+            game_loop
+                .advance_frame(Duration::from_micros(16_670))
+                .unwrap();
+            updates.push(game_loop.state().update - before);
+        }
+
+        assert!(updates.iter().all(|&count| count == 1 || count == 2));
+        assert_eq!(updates.iter().sum::<usize>(), 100);
+        assert_eq!(game_loop.state().update, 100);
+        assert_eq!(game_loop.state().render, 60);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_micros(200));
+    }
+
+    #[test]
+    fn test_game_loop_first_frame_time() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+
+        // Loading the game after constructing the loop takes a while.
+        clock.advance(Duration::from_secs(3));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 0);
+        assert_eq!(game_loop.accumulated_time(), Duration::default());
+
+        // The first measured frame starts when the first tick started.
+        clock.advance(Duration::from_millis(12));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 1);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_game_loop_has_ticked() {
+        let mut game_loop = GameLoop::new(State::default());
+        assert!(!game_loop.has_ticked());
+
+        game_loop.tick().unwrap();
+        assert!(game_loop.has_ticked());
+    }
+
+    #[test]
+    fn test_game_loop_min_frame_time() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.set_min_frame_time(Duration::from_millis(4));
+        game_loop.tick().unwrap();
+
+        for tick in 1..=5 {
+            clock.advance(Duration::from_micros(100));
+            game_loop.tick().unwrap();
+
+            assert_eq!(clock.now(), Duration::from_millis(4 * tick));
+        }
+
+        // 20 milliseconds passed in total, without double-counting the waits.
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(game_loop.accumulated_time(), Duration::default());
+    }
+
+    #[test]
+    fn test_error_into_boxed() {
+        #[derive(Debug)]
+        struct Mismatched;
+
+        impl Updater for Mismatched {
+            type Error = std::io::Error;
+
+            fn update(&mut self) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        impl Renderer for Mismatched {
+            type Error = fmt::Error;
+
+            fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        let update =
+            Error::<Mismatched>::Update(std::io::Error::new(std::io::ErrorKind::Other, "update"))
+                .into_boxed();
+        assert!(update.downcast_ref::<std::io::Error>().is_some());
+
+        let render = Error::<Mismatched>::Render(fmt::Error).into_boxed();
+        assert!(render.downcast_ref::<fmt::Error>().is_some());
+    }
+
+    #[test]
+    fn test_game_loop_render_budget() {
+        use std::sync::Mutex;
+
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+
+        let exceeded = Arc::new(Mutex::new(vec![]));
+        let recorded = Arc::clone(&exceeded);
+        game_loop.set_render_budget(
+            Duration::from_millis(8),
+            Box::new(move |time| recorded.lock().unwrap().push(time)),
+        );
+
+        for render_time in &[5, 12, 8] {
+            game_loop.state().render_work =
+                Some((clock.clone(), Duration::from_millis(*render_time)));
+            game_loop.tick().unwrap();
+        }
+
+        assert_eq!(*exceeded.lock().unwrap(), vec![Duration::from_millis(12)]);
+    }
+
+    #[test]
+    fn test_game_loop_advance_and_render() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.add_accumulated_time(Duration::from_millis(5));
+
+        game_loop.advance_and_render(4).unwrap();
+
+        assert_eq!(game_loop.state().update, 4);
+        assert_eq!(game_loop.state().render, 1);
+        assert!(game_loop.state().remainder.abs() < 1e-6);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_millis(5));
+
+        game_loop.set_render_mode(RenderMode::Extrapolate);
+        game_loop.advance_and_render(1).unwrap();
+        assert_eq!(game_loop.state().render, 2);
+        assert!(game_loop.state().remainder.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_game_loop_timing_fingerprint() {
+        let mut first = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        let mut second = first.clone();
+
+        for delta in &[0, 16, 17, 3, 25] {
+            first
+                .tick_with_delta(Duration::from_millis(*delta))
+                .unwrap();
+            second
+                .tick_with_delta(Duration::from_millis(*delta))
+                .unwrap();
+        }
+        assert_eq!(first.timing_fingerprint(), second.timing_fingerprint());
+
+        second.tick_with_delta(Duration::from_millis(1)).unwrap();
+        assert_ne!(first.timing_fingerprint(), second.timing_fingerprint());
+    }
+
+    #[test]
+    fn test_game_loop_auto_pause_after() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        game_loop.set_auto_pause_after(Some(Duration::from_millis(30)));
+        game_loop.tick().unwrap();
+
+        // The game is inactive for 3 updates, after which the loop pauses,
+        // without running the remaining updates of the tick.
+        game_loop.state().inactive = true;
+        game_loop
+            .tick_with_delta(Duration::from_millis(50))
+            .unwrap();
+        assert!(game_loop.is_paused());
+        assert_eq!(game_loop.state().update, 3);
+        assert_eq!(game_loop.state().pause, 1);
+
+        game_loop
+            .tick_with_delta(Duration::from_millis(50))
+            .unwrap();
+        assert_eq!(game_loop.state().update, 3);
+
+        // Activity resumes the loop on the next tick, which drains the time
+        // left over from before the pause.
+        game_loop.state().inactive = false;
+        game_loop
+            .tick_with_delta(Duration::from_millis(10))
+            .unwrap();
+        assert!(!game_loop.is_paused());
+        assert_eq!(game_loop.state().resume, 1);
+        assert_eq!(game_loop.state().update, 6);
+    }
+
+    #[test]
+    fn test_game_loop_auto_pause_keeps_explicit_pause() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        game_loop.set_auto_pause_after(Some(Duration::from_millis(30)));
+        game_loop.tick().unwrap();
+
+        game_loop.state().inactive = true;
+        game_loop
+            .tick_with_delta(Duration::from_millis(30))
+            .unwrap();
+        assert!(game_loop.is_paused());
+
+        // Pausing the automatically paused loop makes the pause explicit, so
+        // activity doesn't resume it.
+        game_loop.pause();
+        game_loop.state().inactive = false;
+        game_loop
+            .tick_with_delta(Duration::from_millis(10))
+            .unwrap();
+        assert!(game_loop.is_paused());
+        assert_eq!(game_loop.state().resume, 0);
+
+        // Inactive updates of an explicitly paused loop don't make it an
+        // automatic pause either.
+        game_loop.state().inactive = true;
+        game_loop.fast_forward(3).unwrap();
+        game_loop.state().inactive = false;
+        game_loop.tick().unwrap();
+        assert!(game_loop.is_paused());
+    }
 
-                // If enough time has accumulated since the last tick, run the
-                // updater, until it has drained the accumulated time.
-                //
-                // The required accumulated time depends on the configured
-                // updates per second. If set to 100, we have a budget of 10
-                // milliseconds per update, so `accumulated_time` needs to be 10
-                // milliseconds or more to perform another update.
-                //
-                // After updating the game, we keep the [`GameState`] set to
-                // `Updating`, and we try to update the game again, until we run
-                // out of `accumuated_time`.
-                Updating if self.accumulated_time >= self.update_interval => {
-                    self.state.update().map_err(Error::Update)?;
-                    self.accumulated_time -= self.update_interval;
-                }
+    #[test]
+    fn test_game_loop_remainder_percent() {
+        let mut game_loop = GameLoop::new(State::default());
+        assert_eq!(game_loop.remainder_percent(), 0);
 
-                // Once we run out of time to update the game state, move on to
-                // rendering.
-                Updating => {
-                    tick.state = Rendering;
-                }
+        game_loop.add_accumulated_time(Duration::from_millis(9));
+        assert_eq!(game_loop.remainder_percent(), 90);
 
-                // Call the renderer.
-                //
-                // While the `accumulated_time` budget wasn't large enough to
-                // perform another game update, chances are it wasn't exactly
-                // zero once we were done updating the game. This means we're
-                // about to render the game in-between two game updates.
-                //
-                // We pass the "remainder" (a value between 0.0 and 1.0) between
-                // the last update, and the expected next update to the
-                // [`Renderer`], to allow for visual interpolation of the game
-                // state.
-                Rendering => {
-                    self.state.render(self.remainder()).map_err(Error::Render)?;
-                    self.previous_tick = Some(tick);
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+        assert_eq!(game_loop.remainder_percent(), 99);
+    }
 
-                    // We're done with this tick, exit the method.
-                    return Ok(());
+    #[test]
+    fn test_game_loop_run_with_timer() {
+        /// A frame timer reporting 15 milliseconds per frame, stopping the
+        /// game loop on the fifth frame.
+        #[derive(Debug)]
+        struct Vsync {
+            frames: usize,
+            stop_handle: StopHandle,
+        }
+
+        impl FrameTimer for Vsync {
+            fn wait_for_next_frame(&mut self) -> Duration {
+                self.frames += 1;
+                if self.frames == 5 {
+                    self.stop_handle.stop();
                 }
+
+                Duration::from_millis(15)
             }
         }
+
+        let mut game_loop = GameLoop::new(State::default());
+        let mut timer = Vsync {
+            frames: 0,
+            stop_handle: game_loop.stop_handle(),
+        };
+        game_loop.run_with_timer(&mut timer).unwrap();
+
+        // The fifth frame is stopped before it updates the game state.
+        assert_eq!(game_loop.state().update, 6);
+        assert_eq!(game_loop.state().render, 4);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_millis(15));
     }
 
-    /// A helper method to get the remainder stored in the game loop.
-    ///
-    /// This is meant to aid in unit testing the state of the game by inspecting
-    /// how much time is still stored as the remainder of the game loop.
-    pub fn remainder(&self) -> f32 {
-        let remainder = as_secs_f32(self.accumulated_time) / as_secs_f32(self.update_interval);
-        debug_assert!((remainder >= 0.0) && (remainder < 1.0));
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_frame_time_jitter() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.frame_time_jitter(), Duration::default());
+
+        for frame_time in [10, 30].iter().cycle().take(8) {
+            clock.advance(Duration::from_millis(*frame_time));
+            game_loop.tick().unwrap();
+        }
 
-        remainder
+        assert_eq!(game_loop.frame_time_jitter(), Duration::from_millis(10));
     }
 
-    /// A helper method to inspect the game state.
-    ///
-    /// This is meant to aid in unit testing the state of the game by allowing
-    /// inspection (or mutation) of the game state after performing a game tick.
-    pub fn state(&mut self) -> &mut T {
-        &mut self.state
+    #[test]
+    fn test_game_loop_render_requests_extra_update() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        game_loop.state().extra_updates_requested = 1;
+
+        game_loop.add_accumulated_time(Duration::from_millis(15));
+        let report = game_loop.tick_report().unwrap();
+
+        assert_eq!(report.updates, 2);
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(game_loop.state().render, 2);
+        assert_eq!(game_loop.accumulated_time(), Duration::from_millis(5));
+
+        // Requests beyond the cap are ignored.
+        game_loop.state().extra_updates_requested = 10;
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 5);
+        assert_eq!(game_loop.state().render, 6);
     }
 
-    /// A helper method to increase the accumulated time by a fixed amount.
-    ///
-    /// This is meant to aid in unit testing the state of the game by forcing
-    /// the updater to run a fixed amount of times when triggering another game
-    /// tick.
-    pub fn add_accumulated_time(&mut self, add: Duration) {
-        self.accumulated_time += add;
+    #[test]
+    fn test_game_loop_extra_update_guards() {
+        use std::sync::Mutex;
+
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        let boundaries = Arc::new(Mutex::new(vec![]));
+        let recorded = Arc::clone(&boundaries);
+        game_loop.set_on_update_boundary(Box::new(move |index| {
+            recorded.lock().unwrap().push(index);
+        }));
+        game_loop.tick().unwrap();
+
+        // The extra update invokes the update boundary hook.
+        game_loop.state().extra_updates_requested = 1;
+        clock.advance(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 2);
+        assert_eq!(*boundaries.lock().unwrap(), vec![0, 1]);
+
+        // No extra update runs while paused.
+        game_loop.pause();
+        game_loop.state().extra_updates_requested = 1;
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 2);
+        game_loop.resume();
+
+        // Nor once the update ceiling is reached.
+        game_loop.set_max_updates_per_real_second(Some(1));
+        game_loop.state().extra_updates_requested = 1;
+        clock.advance(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 3);
     }
-}
 
-/// Convert a duration to fractional seconds.
-///
-/// See: <https://github.com/rust-lang/rust/pull/62756>
-#[allow(clippy::cast_precision_loss)]
-fn as_secs_f32(duration: Duration) -> f32 {
-    (duration.as_secs() as f32) + (duration.subsec_nanos() as f32) / (NANOSECONDS_PER_SECOND as f32)
-}
+    #[test]
+    fn test_game_loop_with_config() {
+        let config = LoopConfig {
+            updates_per_second: 50,
+            render_every: 2,
+            ..LoopConfig::default()
+        };
+        let mut game_loop = GameLoop::with_config(State::default(), &config).unwrap();
+        assert_eq!(game_loop.update_interval(), Duration::from_millis(20));
 
-#[cfg(test)]
-#[allow(clippy::result_unwrap_used)]
-mod tests {
-    use super::*;
+        game_loop.tick().unwrap();
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().render, 1);
+    }
 
-    #[derive(Debug, Default)]
-    struct State {
-        update: usize,
-        render: usize,
+    #[test]
+    fn test_game_loop_backward_time_policy() {
+        let clock = ManualClock::new();
+        clock.advance(Duration::from_secs(1));
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .backward_time_policy(BackwardTimePolicy::Error)
+            .build(State::default())
+            .unwrap();
+        game_loop.tick().unwrap();
+
+        clock.set(Duration::from_millis(980));
+        match game_loop.tick() {
+            Err(Error::BackwardTime(went_back)) => {
+                assert_eq!(went_back, Duration::from_millis(20));
+            }
+            Ok(()) => panic!("the tick unexpectedly succeeded"),
+            Err(Error::Update(_)) | Err(Error::Render(_)) => panic!("unexpected error"),
+        }
+
+        // The game loop measures from the failed tick onwards.
+        clock.advance(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 1);
     }
 
-    impl Updater for State {
-        type Error = std::io::Error;
+    #[test]
+    fn test_game_loop_audio_interpolation() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        game_loop.tick().unwrap();
 
-        fn update(&mut self) -> Result<(), Self::Error> {
-            self.update += 1;
-            Ok(())
+        game_loop
+            .tick_with_delta(Duration::from_millis(35))
+            .unwrap();
+        let (sequence, remainder) = game_loop.audio_interpolation();
+
+        assert_eq!(sequence, 3);
+        assert!((remainder - game_loop.state().remainder).abs() < 1e-6);
+        assert!((remainder - 0.5).abs() < 1e-6);
+
+        // The remainder follows the render mode, like the renderer's.
+        game_loop.set_render_mode(RenderMode::Extrapolate);
+        let (_, audio_remainder) = game_loop.audio_interpolation();
+        assert!((audio_remainder - 1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_game_loop_max_render_fps() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        game_loop.set_max_render_fps(Some(30));
+
+        // Tick every millisecond for 100 milliseconds.
+        let mut rendered_at = vec![];
+        for millisecond in 0..=100 {
+            let render = game_loop.state().render;
+            game_loop.tick_with_delta(Duration::from_millis(1)).unwrap();
+            if game_loop.state().render > render {
+                rendered_at.push(millisecond);
+            }
         }
+
+        assert_eq!(rendered_at, vec![0, 34, 67, 100]);
+        assert_eq!(game_loop.state().update, 10);
     }
 
-    impl Renderer for State {
-        type Error = std::io::Error;
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_update_render_ratio() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        assert!(game_loop.update_render_ratio().abs() < std::f32::EPSILON);
 
-        fn render(&mut self, _remainder: f32) -> Result<(), Self::Error> {
-            self.render += 1;
-            Ok(())
+        game_loop.state().work = Some((clock.clone(), Duration::from_millis(2)));
+        game_loop.state().render_work = Some((clock, Duration::from_millis(8)));
+        for _ in 0..10 {
+            game_loop.add_accumulated_time(Duration::from_millis(10));
+            game_loop.tick().unwrap();
         }
+
+        assert!((game_loop.update_render_ratio() - 0.25).abs() < 1e-6);
     }
 
     #[test]
-    fn test_game_loop_state() {
-        let mut game_loop = GameLoop::new(State {
-            update: 1,
-            render: 2,
-        });
+    fn test_game_loop_skip_time() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        game_loop.set_max_frame_time(Some(Duration::from_millis(250)));
 
-        assert_eq!(game_loop.state().update, 1);
+        game_loop.skip_time(Duration::from_secs(60 * 60));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().update, 25);
+
+        // A real stall is clamped just the same.
+        game_loop.tick_with_delta(Duration::from_secs(60)).unwrap();
+        assert_eq!(game_loop.state().update, 50);
+    }
+
+    #[test]
+    fn test_game_loop_tick_observed() {
+        let mut game_loop = GameLoop::new(State::default());
+        let mut updates = vec![];
+        let mut renders = vec![];
+
+        game_loop.add_accumulated_time(Duration::from_millis(25));
+        game_loop
+            .tick_observed(
+                |sequence| updates.push(sequence),
+                |remainder| renders.push(remainder),
+            )
+            .unwrap();
+
+        assert_eq!(updates, vec![1, 2]);
+        assert_eq!(renders.len(), 1);
+    }
+
+    #[test]
+    fn test_game_loop_remainder_of_multi_hour_duration() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.add_accumulated_time(Duration::from_secs(3 * 60 * 60) + Duration::from_millis(5));
+
+        let remainder = game_loop.raw_remainder();
+        assert!(remainder.is_finite());
+        assert!((remainder - 1_080_000.5).abs() / 1_080_000.5 < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_bottleneck() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        assert_eq!(game_loop.bottleneck(), Bottleneck::WithinBudget);
+
+        game_loop.state().render_work = Some((clock.clone(), Duration::from_millis(4)));
+        for _ in 0..4 {
+            game_loop.tick().unwrap();
+        }
+        assert_eq!(game_loop.bottleneck(), Bottleneck::WithinBudget);
+
+        game_loop.state().render_work = Some((clock, Duration::from_millis(25)));
+        for _ in 0..8 {
+            game_loop.tick().unwrap();
+        }
+        assert_eq!(game_loop.bottleneck(), Bottleneck::Render);
+    }
+
+    #[test]
+    fn test_game_loop_suppress_render_errors() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.set_suppress_render_errors(Duration::from_millis(100));
+
+        assert_eq!(
+            game_loop.handle_event(LoopEvent::Resized).unwrap(),
+            Flow::Continue
+        );
+        game_loop.state().render_failures = 1;
+        clock.advance(Duration::from_millis(10));
+        let report = game_loop.tick_report().unwrap();
+        assert!(!report.rendered);
+        assert!(!report.presented);
+        assert_eq!(report.suppressed_render_errors, 1);
+        assert_eq!(game_loop.state().render, 0);
+
+        clock.advance(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().render, 1);
+
+        game_loop.state().render_failures = 1;
+        clock.advance(Duration::from_millis(100));
+        assert!(game_loop.tick().is_err());
+    }
+
+    /// Without the `stats` feature, the accessors of the metrics don't exist,
+    /// so this test only compiles if they are gated, and only uses `tick`.
+    #[test]
+    #[cfg(not(feature = "stats"))]
+    fn test_game_loop_ticks_without_metrics() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.tick().unwrap();
+
+        clock.advance(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().update, 2);
         assert_eq!(game_loop.state().render, 2);
     }
 
     #[test]
-    fn test_game_loop_tick_drains_accumulated_time() {
+    fn test_game_loop_time_to_next_render() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        assert_eq!(game_loop.time_to_next_render(), None);
+
+        game_loop.set_max_render_fps(Some(30));
+        assert_eq!(game_loop.time_to_next_render(), Some(Duration::default()));
+        game_loop.tick().unwrap();
+
+        clock.advance(Duration::from_millis(10));
+        game_loop.tick().unwrap();
+        assert_eq!(game_loop.state().render, 1);
+        assert_eq!(
+            game_loop.time_to_next_render(),
+            Some(Duration::from_nanos(23_333_333))
+        );
+    }
+
+    #[test]
+    fn test_game_loop_render_clock() {
+        let update_clock = ManualClock::new();
+        let render_clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(update_clock.clone())
+            .render_clock(render_clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.set_max_render_fps(Some(50));
+        game_loop.tick().unwrap();
+
+        // The simulation runs at twice the speed of the display.
+        for _ in 0..8 {
+            update_clock.advance(Duration::from_millis(10));
+            render_clock.advance(Duration::from_millis(5));
+            game_loop.tick().unwrap();
+        }
+
+        assert_eq!(game_loop.state().update, 8);
+        assert_eq!(game_loop.state().render, 3);
+    }
+
+    #[test]
+    fn test_game_loop_catch_up_to() {
         let mut game_loop = GameLoop::new(State::default());
 
-        // we run at 100 FPS, so update the game state every 10ms
-        game_loop.add_accumulated_time(Duration::from_millis(10));
+        let updates = game_loop.catch_up_to(Duration::from_millis(100)).unwrap();
+
+        assert_eq!(updates, 10);
+        assert_eq!(game_loop.state().update, 10);
+        assert_eq!(game_loop.state().render, 0);
+        assert_eq!(game_loop.simulation_time(), Duration::from_millis(100));
+        assert_eq!(game_loop.catch_up_to(Duration::from_millis(50)).unwrap(), 0);
+
+        let capped = game_loop.catch_up_to(Duration::from_secs(3600)).unwrap();
+        assert_eq!(capped, MAX_CATCH_UP_TO_UPDATES);
+    }
+
+    #[test]
+    fn test_game_loop_max_updates_per_real_second() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        game_loop.set_max_updates_per_real_second(Some(20));
         game_loop.tick().unwrap();
-        assert_eq!(game_loop.state().update, 1);
 
-        // At the last tick, the updater ran once, and drained all accumulated
-        // time. We add 6 more milliseconds, bringing the total to 6, so no new
-        // update is triggered.
-        game_loop.add_accumulated_time(Duration::from_millis(6));
+        let mut updates = vec![];
+        for delta in &[100, 100, 100, 1000] {
+            clock.advance(Duration::from_millis(*delta));
+            updates.push(game_loop.tick_report().unwrap());
+        }
+
+        let counts: Vec<_> = updates.iter().map(|report| report.updates).collect();
+        assert_eq!(counts, vec![10, 10, 0, 20]);
+        assert_eq!(updates[2].reason, TickReason::CappedCatchUp);
+    }
+
+    #[test]
+    fn test_game_loop_catch_up_callbacks() {
+        use std::sync::Mutex;
+
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+
+        let events = Arc::new(Mutex::new(vec![]));
+        let started = Arc::clone(&events);
+        game_loop.set_on_catch_up_start(Box::new(move || started.lock().unwrap().push("start")));
+        let ended = Arc::clone(&events);
+        game_loop.set_on_catch_up_end(Box::new(move || ended.lock().unwrap().push("end")));
+        game_loop.tick().unwrap();
+
+        // A single slow tick isn't enough to start catching up, nor is a single
+        // fast tick enough to stop.
+        for delta in &[30, 10, 30, 30, 30, 10, 30, 10, 10, 10] {
+            clock.advance(Duration::from_millis(*delta));
+            game_loop.tick().unwrap();
+        }
+
+        assert_eq!(*events.lock().unwrap(), vec!["start", "end"]);
+    }
+
+    #[test]
+    fn test_game_loop_max_accumulated_intervals() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.set_max_accumulated_intervals(Some(3));
+
+        game_loop.add_accumulated_time(Duration::from_secs(5));
+        let report = game_loop.tick_report().unwrap();
+
+        assert_eq!(report.updates, 3);
+        assert_eq!(game_loop.accumulated_time(), Duration::default());
+    }
+
+    #[test]
+    fn test_game_loop_max_updates_per_tick() {
+        let mut game_loop = GameLoop::new(State::default());
+        assert_eq!(game_loop.max_updates_per_tick(), None);
+
+        game_loop.set_max_updates_per_tick(Some(5));
+        assert_eq!(game_loop.max_updates_per_tick(), Some(5));
+    }
+
+    #[test]
+    fn test_game_loop_render_mode() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.set_render_mode(RenderMode::Extrapolate);
+
+        game_loop.add_accumulated_time(Duration::from_millis(15));
         game_loop.tick().unwrap();
+
         assert_eq!(game_loop.state().update, 1);
+        assert!((game_loop.state().remainder - 1.5).abs() < 1e-6);
 
-        // We still have 6 milliseconds accumulated, by adding 16 more, we end
-        // up with 22, so the updater runs twice, leaving 2 accumulated
-        // milliseconds.
-        game_loop.add_accumulated_time(Duration::from_millis(16));
+        game_loop.set_render_mode(RenderMode::Interpolate);
+        game_loop.render_now().unwrap();
+        assert!((game_loop.state().remainder - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_game_loop_suspend() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
         game_loop.tick().unwrap();
-        assert_eq!(game_loop.state().update, 3);
+
+        game_loop.suspend();
+        assert!(game_loop.is_suspended());
+        clock.advance(Duration::from_secs(3600));
+        game_loop.resume();
+
+        assert_eq!(game_loop.tick_report().unwrap().updates, 0);
+        assert_eq!(game_loop.state().pause, 1);
+        assert_eq!(game_loop.state().resume, 1);
+        assert!(!game_loop.is_suspended());
+        assert!(game_loop.has_ticked());
+
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(game_loop.tick_report().unwrap().updates, 1);
     }
 
     #[test]
-    #[allow(clippy::float_cmp)]
-    fn test_game_loop_remainder() {
+    fn test_game_loop_tick_at() {
         let mut game_loop = GameLoop::new(State::default());
+        let start = Instant::now();
 
-        game_loop.add_accumulated_time(Duration::from_millis(9));
-        assert_eq!(game_loop.remainder(), 0.9);
+        let expected = [(0, 0), (10, 1), (35, 2), (40, 1), (40, 0), (75, 3)];
+        for (millis, updates) in &expected {
+            game_loop
+                .tick_at(start + Duration::from_millis(*millis))
+                .unwrap();
+
+            assert_eq!(game_loop.state().update, *updates);
+            game_loop.state().update = 0;
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn test_game_loop_invalid_remainder() {
+    fn test_game_loop_quantized_remainder() {
         let mut game_loop = GameLoop::new(State::default());
+        game_loop.add_accumulated_time(Duration::from_micros(3100));
 
-        game_loop.add_accumulated_time(Duration::from_millis(10));
+        assert!((game_loop.quantized_remainder(4) - 0.25).abs() < 1e-6);
+        assert!((game_loop.quantized_remainder(1) - 0.0).abs() < 1e-6);
 
-        // The remainder has to be 0.0 or higher, and lower than 1.0 to be
-        // valid. The only way this invalid state can be triggered is if the
-        // `add_accumulated_time` is used to manually add 10 or more
-        // milliseconds, without using `tick` to consume that accumulated time
-        // down to below 10.
-        let _ = game_loop.remainder();
+        game_loop.add_accumulated_time(Duration::from_micros(6000));
+        assert!((game_loop.quantized_remainder(4) - 0.75).abs() < 1e-6);
     }
 
     #[test]
-    fn test_game_loop_tick_runs_renderer() {
-        let mut game_loop = GameLoop::new(State::default());
+    fn test_game_loop_on_second() {
+        use std::sync::Mutex;
 
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+
+        let reports = Arc::new(Mutex::new(vec![]));
+        let seconds = Arc::clone(&reports);
+        game_loop.set_on_second(Box::new(move |report| {
+            seconds.lock().unwrap().push(report.updates)
+        }));
         game_loop.tick().unwrap();
 
-        assert_eq!(game_loop.state().render, 1);
+        for delta in &[600, 600, 300] {
+            clock.advance(Duration::from_millis(*delta));
+            game_loop.tick().unwrap();
+        }
+
+        assert_eq!(*reports.lock().unwrap(), vec![60]);
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn test_game_loop_update_headroom() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        assert!((game_loop.update_headroom() - 1.0).abs() < std::f32::EPSILON);
+
+        game_loop.state().work = Some((clock, Duration::from_millis(1)));
+        for _ in 0..10 {
+            game_loop.add_accumulated_time(Duration::from_millis(10));
+            game_loop.tick().unwrap();
+        }
+
+        assert!((game_loop.update_headroom() - 0.9).abs() < 1e-6);
     }
 }
@@ -0,0 +1,83 @@
+//! The module implementing [`Interpolate`] for the vector and quaternion types
+//! of the `glam` crate.
+
+use crate::{Interpolate, Remainder};
+use glam::{Quat, Vec2, Vec3, Vec4};
+
+/// The remainder as an `f32`, the precision `glam` works with.
+fn factor(remainder: Remainder) -> f32 {
+    0.0_f32.interpolate(&1.0, remainder)
+}
+
+impl Interpolate for Vec2 {
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self {
+        *self + (*other - *self) * factor(remainder)
+    }
+}
+
+impl Interpolate for Vec3 {
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self {
+        *self + (*other - *self) * factor(remainder)
+    }
+}
+
+impl Interpolate for Vec4 {
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self {
+        *self + (*other - *self) * factor(remainder)
+    }
+}
+
+/// Rotations are interpolated using spherical linear interpolation (slerp),
+/// along the shortest path between the two rotations.
+impl Interpolate for Quat {
+    fn interpolate(&self, other: &Self, remainder: Remainder) -> Self {
+        let t = factor(remainder);
+        let from = Vec4::from(*self);
+        let mut to = Vec4::from(*other);
+
+        let mut dot = from.dot(to);
+        if dot < 0.0 {
+            to = to * -1.0;
+            dot = -dot;
+        }
+
+        // For (nearly) identical rotations, the angle between the two is too
+        // small to divide by, so we fall back to a normalized linear blend.
+        if dot > 0.9995 {
+            return Quat::from((from + (to - from) * t).normalize());
+        }
+
+        let angle = dot.acos();
+        let blended =
+            (from * ((1.0 - t) * angle).sin() + to * (t * angle).sin()) * angle.sin().recip();
+
+        Quat::from(blended)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpolate_between;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    #[test]
+    fn test_interpolate_vec2() {
+        let position = interpolate_between(&Vec2::new(0.0, 10.0), &Vec2::new(4.0, 30.0), 0.25);
+
+        assert!((position - Vec2::new(1.0, 15.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_interpolate_quat_slerp() {
+        let from = Quat::identity();
+        let to = Quat::from_rotation_z(FRAC_PI_2);
+
+        let rotation = interpolate_between(&from, &to, 0.5);
+
+        assert!((Vec4::from(rotation).length() - 1.0).abs() < 1e-6);
+        assert!(
+            Vec4::from(rotation).dot(Vec4::from(Quat::from_rotation_z(FRAC_PI_4))) > 1.0 - 1e-6
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! The module keeping track of the metrics of the [`GameLoop`].
+//!
+//! Metrics are only collected with the `stats` feature enabled. Without it,
+//! this module and the accessors of the metrics are compiled out, and ticks
+//! don't time their updates and renders.
+//!
+//! [`GameLoop`]: crate::GameLoop
+
+use crate::{Bottleneck, TickReport};
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::time::Duration;
+use std::vec;
+
+/// The maximum amount of tick reports kept around until they are drained.
+///
+/// If the metrics are never drained, the oldest reports are dropped, to prevent
+/// the game loop from using an ever-growing amount of memory.
+pub(crate) const MAX_PENDING_REPORTS: usize = 1024;
+
+/// The amount of frame times the rolling average frame time is computed from.
+pub(crate) const FRAME_TIME_SAMPLES: usize = 8;
+
+/// The amount of update and render times their rolling averages are computed
+/// from.
+pub(crate) const PHASE_TIME_SAMPLES: usize = 8;
+
+/// A batch of tick reports, collected since the metrics were last drained.
+///
+/// See [`GameLoop::drain_metrics`].
+///
+/// [`GameLoop::drain_metrics`]: crate::GameLoop::drain_metrics
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsBatch {
+    /// The reports of the ticks, oldest first.
+    pub reports: Vec<TickReport>,
+
+    /// The amount of (oldest) reports that were dropped, because the metrics
+    /// weren't drained in time.
+    pub dropped: usize,
+}
+
+impl MetricsBatch {
+    /// The amount of tick reports in the batch.
+    pub fn len(&self) -> usize {
+        self.reports.len()
+    }
+
+    /// Whether the batch contains no tick reports.
+    pub fn is_empty(&self) -> bool {
+        self.reports.is_empty()
+    }
+}
+
+impl IntoIterator for MetricsBatch {
+    type Item = TickReport;
+    type IntoIter = vec::IntoIter<TickReport>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.reports.into_iter()
+    }
+}
+
+/// The metrics collected by the game loop.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Metrics {
+    /// The tick reports collected since the last drain.
+    pending: VecDeque<TickReport>,
+
+    /// The amount of reports dropped since the last drain.
+    dropped: usize,
+
+    /// The amount of stutters reported by the game state.
+    ///
+    /// Unlike the tick reports, this count is never drained.
+    stutters: usize,
+
+    /// The most recently measured frames, oldest first.
+    frames: VecDeque<Frame>,
+
+    /// The longest time a single update took.
+    ///
+    /// Like the stutter count, this is never drained.
+    peak_update_time: Duration,
+
+    /// The most recently measured times of single updates, oldest first.
+    update_times: VecDeque<Duration>,
+
+    /// The most recently measured times of single renders, oldest first.
+    render_times: VecDeque<Duration>,
+}
+
+/// A single frame, measured between two ticks.
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    /// The time measured between the start of the previous tick, and the start
+    /// of the tick.
+    time: Duration,
+
+    /// The amount of updates run by the tick.
+    updates: usize,
+}
+
+impl Metrics {
+    /// Create empty metrics, with every buffer allocated to hold its full
+    /// window.
+    pub(crate) fn preallocated() -> Self {
+        Self {
+            pending: VecDeque::with_capacity(MAX_PENDING_REPORTS),
+            frames: VecDeque::with_capacity(FRAME_TIME_SAMPLES),
+            update_times: VecDeque::with_capacity(PHASE_TIME_SAMPLES),
+            render_times: VecDeque::with_capacity(PHASE_TIME_SAMPLES),
+            ..Self::default()
+        }
+    }
+
+    /// The capacity of the buffers holding the pending reports, the frames,
+    /// and the (smallest of the) update and render times.
+    #[cfg(test)]
+    pub(crate) fn capacities(&self) -> (usize, usize, usize) {
+        (
+            self.pending.capacity(),
+            self.frames.capacity(),
+            self.update_times
+                .capacity()
+                .min(self.render_times.capacity()),
+        )
+    }
+
+    /// Record the report of a completed tick.
+    pub(crate) fn record(&mut self, report: &TickReport) {
+        if self.pending.len() == MAX_PENDING_REPORTS {
+            let _ = self.pending.pop_front();
+            self.dropped += 1;
+        }
+
+        self.pending.push_back(report.clone());
+    }
+
+    /// Record a stutter, reported by the game state after an update.
+    pub(crate) fn record_stutter(&mut self) {
+        self.stutters += 1;
+    }
+
+    /// The amount of stutters recorded so far.
+    pub(crate) const fn stutter_count(&self) -> usize {
+        self.stutters
+    }
+
+    /// Record the time a single update took.
+    pub(crate) fn record_update_time(&mut self, time: Duration) {
+        self.peak_update_time = self.peak_update_time.max(time);
+        record_sample(&mut self.update_times, time);
+    }
+
+    /// Record the time a single render took.
+    pub(crate) fn record_render_time(&mut self, time: Duration) {
+        record_sample(&mut self.render_times, time);
+    }
+
+    /// The average time of the recorded updates, divided by the average time
+    /// of the recorded renders, or `0.0` if no time was spent rendering.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn update_render_ratio(&self) -> f32 {
+        let render = average(&self.render_times);
+        if render == 0.0 {
+            return 0.0;
+        }
+
+        (average(&self.update_times) / render) as f32
+    }
+
+    /// The fraction of the given update interval left unused by the average
+    /// of the recorded updates, from `0.0` up to and including `1.0`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub(crate) fn update_headroom(&self, interval: Duration) -> f32 {
+        if self.update_times.is_empty() || interval == Duration::default() {
+            return 1.0;
+        }
+
+        (1.0 - average(&self.update_times) / interval.as_nanos() as f64).max(0.0) as f32
+    }
+
+    /// Classify the recorded frames against the given frame budget, see
+    /// [`GameLoop::bottleneck`].
+    ///
+    /// [`GameLoop::bottleneck`]: crate::GameLoop::bottleneck
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub(crate) fn bottleneck(&self, budget: Duration) -> Bottleneck {
+        let updates_per_frame = if self.frames.is_empty() {
+            1.0
+        } else {
+            self.frames.iter().map(|frame| frame.updates).sum::<usize>() as f64
+                / self.frames.len() as f64
+        };
+
+        let update = average(&self.update_times) * updates_per_frame;
+        let render = average(&self.render_times);
+
+        if update + render <= budget.as_nanos() as f64 {
+            Bottleneck::WithinBudget
+        } else if update > render {
+            Bottleneck::Update
+        } else {
+            Bottleneck::Render
+        }
+    }
+
+    /// The longest time a single update took so far.
+    pub(crate) const fn peak_update_time(&self) -> Duration {
+        self.peak_update_time
+    }
+
+    /// Record the time measured between two ticks, and the amount of updates
+    /// run by the latter tick.
+    pub(crate) fn record_frame(&mut self, time: Duration, updates: usize) {
+        if self.frames.len() == FRAME_TIME_SAMPLES {
+            let _ = self.frames.pop_front();
+        }
+
+        self.frames.push_back(Frame { time, updates });
+    }
+
+    /// The rolling average of the recorded frame times, or `None` if fewer
+    /// than [`FRAME_TIME_SAMPLES`] frames were recorded so far.
+    pub(crate) fn average_frame_time(&self) -> Option<Duration> {
+        if self.frames.len() < FRAME_TIME_SAMPLES {
+            return None;
+        }
+
+        let samples = u32::try_from(self.frames.len()).ok()?;
+        Some(self.frames.iter().map(|frame| frame.time).sum::<Duration>() / samples)
+    }
+
+    /// The (population) standard deviation of the recorded frame times, or
+    /// zero if fewer than two frames were recorded so far.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub(crate) fn frame_time_jitter(&self) -> Duration {
+        if self.frames.len() < 2 {
+            return Duration::default();
+        }
+
+        let samples = self.frames.len() as f64;
+        let times = self.frames.iter().map(|frame| frame.time.as_nanos() as f64);
+        let mean = times.clone().sum::<f64>() / samples;
+        let variance = times.map(|time| (time - mean).powi(2)).sum::<f64>() / samples;
+
+        Duration::from_nanos(variance.sqrt() as u64)
+    }
+
+    /// The amount of updates per second over the recorded frames, or `0.0` if
+    /// no time passed during the recorded frames.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub(crate) fn update_rate(&self) -> f32 {
+        let time: Duration = self.frames.iter().map(|frame| frame.time).sum();
+        let updates: usize = self.frames.iter().map(|frame| frame.updates).sum();
+
+        if time == Duration::default() {
+            return 0.0;
+        }
+
+        (updates as f64 / time.as_nanos() as f64 * 1e9) as f32
+    }
+
+    /// Forget all recorded frames, starting the rolling averages over.
+    pub(crate) fn clear_frames(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Forget all recorded update and render times.
+    pub(crate) fn clear_phase_times(&mut self) {
+        self.update_times.clear();
+        self.render_times.clear();
+    }
+
+    /// Take all collected reports, leaving none behind.
+    pub(crate) fn drain(&mut self) -> MetricsBatch {
+        MetricsBatch {
+            reports: self.pending.drain(..).collect(),
+            dropped: std::mem::replace(&mut self.dropped, 0),
+        }
+    }
+}
+
+/// The average of the given times in nanoseconds, or `0.0` if there are none.
+#[allow(clippy::cast_precision_loss)]
+fn average(times: &VecDeque<Duration>) -> f64 {
+    times.iter().map(Duration::as_nanos).sum::<u128>() as f64 / times.len().max(1) as f64
+}
+
+/// Record a sample in the given rolling window of samples, dropping the oldest
+/// sample once the window holds [`PHASE_TIME_SAMPLES`] samples.
+fn record_sample(samples: &mut VecDeque<Duration>, sample: Duration) {
+    if samples.len() == PHASE_TIME_SAMPLES {
+        let _ = samples.pop_front();
+    }
+
+    samples.push_back(sample);
+}
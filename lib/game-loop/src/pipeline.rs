@@ -0,0 +1,188 @@
+//! The module containing the pipeline to tick multiple [`GameLoop`]s in
+//! sequence.
+
+use crate::{Clock, GameLoop, ManualClock, Renderer, TickReport, Updater};
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+/// The boxed error of a single stage of a [`LoopPipeline`].
+type StageError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// An ordered set of game loops, ticked one after the other, sharing a single
+/// clock.
+///
+/// This suits layered architectures, where the output of one game loop (such
+/// as a world simulation) is the input of the next one (such as a presentation
+/// simulation), passed along through channels, or shared state. Because all
+/// stages read the same clock, they stay in lockstep.
+#[derive(Debug, Default)]
+pub struct LoopPipeline {
+    /// The stages, in the order they tick.
+    stages: Vec<Box<dyn Stage>>,
+}
+
+/// The error returned when a stage of a [`LoopPipeline`] fails to tick.
+#[derive(Debug)]
+pub struct PipelineError {
+    /// The index of the stage that failed.
+    pub stage: usize,
+
+    /// The error of the stage, see [`Error::into_boxed`].
+    ///
+    /// [`Error::into_boxed`]: crate::Error::into_boxed
+    pub error: StageError,
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "stage {} of the pipeline failed: {}",
+            self.stage, self.error
+        )
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
+/// A single, type-erased, game loop of a pipeline.
+trait Stage: Debug {
+    /// Run a single tick, see [`GameLoop::tick_report`].
+    fn tick_stage(&mut self) -> Result<TickReport, StageError>;
+
+    /// The clock of the game loop.
+    fn clock(&self) -> (Arc<dyn Clock>, Option<ManualClock>);
+
+    /// Replace the clock of the game loop.
+    fn set_clock(&mut self, clock: Arc<dyn Clock>, manual_clock: Option<ManualClock>);
+}
+
+impl<T> Stage for GameLoop<T>
+where
+    T: Updater + Renderer,
+    <T as Updater>::Error: Send + Sync + 'static,
+    <T as Renderer>::Error: Send + Sync + 'static,
+{
+    fn tick_stage(&mut self) -> Result<TickReport, StageError> {
+        self.tick_report().map_err(crate::Error::into_boxed)
+    }
+
+    fn clock(&self) -> (Arc<dyn Clock>, Option<ManualClock>) {
+        (Arc::clone(&self.clock), self.manual_clock.clone())
+    }
+
+    fn set_clock(&mut self, clock: Arc<dyn Clock>, manual_clock: Option<ManualClock>) {
+        self.clock = clock;
+        self.manual_clock = manual_clock;
+    }
+}
+
+impl LoopPipeline {
+    /// Create an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a game loop as the last stage of the pipeline.
+    ///
+    /// The first stage provides the clock of the pipeline, every later stage
+    /// reads the clock of the first stage instead of its own, see
+    /// [`GameLoop::with_shared_clock`].
+    pub fn push<T>(&mut self, mut game_loop: GameLoop<T>)
+    where
+        T: Updater + Renderer + 'static,
+        <T as Updater>::Error: Send + Sync + 'static,
+        <T as Renderer>::Error: Send + Sync + 'static,
+    {
+        if let Some(first) = self.stages.first() {
+            let (clock, manual_clock) = first.clock();
+            game_loop.set_clock(clock, manual_clock);
+        }
+
+        self.stages.push(Box::new(game_loop));
+    }
+
+    /// The amount of stages in the pipeline.
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Whether the pipeline has no stages.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Tick every stage once, in order, returning the report of every stage.
+    ///
+    /// If a stage fails, the stages after it don't tick.
+    pub fn tick(&mut self) -> Result<Vec<TickReport>, PipelineError> {
+        self.stages
+            .iter_mut()
+            .enumerate()
+            .map(|(stage, game_loop)| {
+                game_loop
+                    .tick_stage()
+                    .map_err(|error| PipelineError { stage, error })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{GameLoopBuilder, Remainder};
+    use std::time::Duration;
+
+    #[derive(Debug, Default)]
+    struct State {
+        update: usize,
+    }
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.update += 1;
+            Ok(())
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_loop_pipeline_ticks_stages_in_order() {
+        let clock = ManualClock::new();
+        let mut world = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(State::default())
+            .unwrap();
+        world.set_label("world");
+        let mut presentation = GameLoopBuilder::new()
+            .updates_per_second(50)
+            .build(State::default())
+            .unwrap();
+        presentation.set_label("presentation");
+
+        let mut pipeline = LoopPipeline::new();
+        pipeline.push(world);
+        pipeline.push(presentation);
+        let _ = pipeline.tick().unwrap();
+
+        clock.advance(Duration::from_millis(45));
+        let reports = pipeline.tick().unwrap();
+
+        let stages: Vec<_> = reports
+            .iter()
+            .map(|report| (report.label.as_ref().map(|label| &**label), report.updates))
+            .collect();
+        assert_eq!(stages, vec![(Some("world"), 4), (Some("presentation"), 2)]);
+    }
+}
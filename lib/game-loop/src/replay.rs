@@ -0,0 +1,187 @@
+//! The module containing the driver to record and replay a [`GameLoop`].
+
+use crate::{ClockSource, Error, GameLoop, GameLoopBuilder, InvariantViolation, Renderer, Updater};
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::Duration;
+
+/// A driver that records the time deltas of every tick of a game loop, so the
+/// game can be replayed, and moved back and forth in time.
+///
+/// Game loops driven by the same sequence of time deltas end up in the same
+/// state, as long as the game state is deterministic. The driver uses this to
+/// reconstruct the state of the game at any recorded tick, by starting over
+/// from a fresh game state, and replaying the recorded deltas up to that tick.
+/// This is the basis of a time-travel debugger.
+///
+/// The game loop is always configured with the [`ClockSource::Manual`] clock
+/// source, so it never reads a real clock.
+pub struct ReplayDriver<T, F>
+where
+    T: Updater + Renderer,
+    F: Fn() -> T,
+{
+    /// The builder used to create a fresh game loop.
+    builder: GameLoopBuilder,
+
+    /// Creates a fresh game state.
+    new_state: F,
+
+    /// The recorded time deltas, one per tick.
+    deltas: Vec<Duration>,
+
+    /// The game loop being driven.
+    game_loop: GameLoop<T>,
+
+    /// The amount of recorded ticks the game loop ran.
+    position: usize,
+}
+
+impl<T, F> ReplayDriver<T, F>
+where
+    T: Updater + Renderer,
+    F: Fn() -> T,
+{
+    /// Create a new driver, using the given builder and function to create a
+    /// fresh game loop, whenever the game is replayed.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the game loop can't be built, see [`GameLoopBuilder::build`].
+    pub fn new(builder: GameLoopBuilder, new_state: F) -> Result<Self, InvariantViolation> {
+        let builder = builder.clock_source(ClockSource::Manual);
+        let game_loop = builder.clone().build(new_state())?;
+
+        Ok(Self {
+            builder,
+            new_state,
+            deltas: vec![],
+            game_loop,
+            position: 0,
+        })
+    }
+
+    /// Run and record a single tick, after the given time delta.
+    ///
+    /// If the driver moved back in time, the recorded ticks after the current
+    /// one are discarded, and the recording continues from here.
+    pub fn tick(&mut self, delta: Duration) -> Result<(), Error<T>> {
+        self.deltas.truncate(self.position);
+        self.deltas.push(delta);
+        self.position += 1;
+
+        self.game_loop.tick_with_delta(delta)
+    }
+
+    /// Reconstruct the game at the given recorded tick, by replaying the
+    /// recorded deltas on a fresh game loop.
+    ///
+    /// Tick zero is the game before its first tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given tick wasn't recorded.
+    pub fn goto(&mut self, tick: u64) -> Result<(), Error<T>> {
+        let position = usize::try_from(tick)
+            .ok()
+            .filter(|position| *position <= self.deltas.len())
+            .expect("the replay can only go to a recorded tick");
+
+        self.game_loop = self
+            .builder
+            .clone()
+            .build((self.new_state)())
+            .expect("the builder was validated when creating the driver");
+        self.position = 0;
+
+        for delta in self.deltas.iter().take(position) {
+            self.game_loop.tick_with_delta(*delta)?;
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+
+    /// The recorded time deltas, one per tick.
+    pub fn deltas(&self) -> &[Duration] {
+        &self.deltas
+    }
+
+    /// The recorded tick the game loop is currently at.
+    pub fn position(&self) -> u64 {
+        u64::try_from(self.position).unwrap_or(u64::max_value())
+    }
+
+    /// The game loop being driven.
+    pub fn game_loop(&mut self) -> &mut GameLoop<T> {
+        &mut self.game_loop
+    }
+}
+
+impl<T, F> fmt::Debug for ReplayDriver<T, F>
+where
+    T: Updater + Renderer,
+    F: Fn() -> T,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReplayDriver")
+            .field("builder", &self.builder)
+            .field("deltas", &self.deltas)
+            .field("game_loop", &self.game_loop)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::Remainder;
+
+    #[derive(Debug, Default)]
+    struct State {
+        update: usize,
+        render: usize,
+    }
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.update += 1;
+            Ok(())
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+            self.render += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_replay_driver_goto() {
+        let deltas: Vec<_> = (0..10).map(|i| Duration::from_millis(7 * i)).collect();
+        let mut driver = ReplayDriver::new(GameLoopBuilder::new(), State::default).unwrap();
+        for delta in &deltas {
+            driver.tick(*delta).unwrap();
+        }
+
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(State::default())
+            .unwrap();
+        for delta in deltas.iter().take(5) {
+            game_loop.tick_with_delta(*delta).unwrap();
+        }
+
+        driver.goto(5).unwrap();
+        assert_eq!(driver.position(), 5);
+        assert_eq!(driver.game_loop().state().update, game_loop.state().update);
+        assert_eq!(driver.game_loop().state().render, 5);
+    }
+}
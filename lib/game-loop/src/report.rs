@@ -0,0 +1,124 @@
+//! The module containing the report of a single tick of the [`GameLoop`].
+//!
+//! [`GameLoop`]: crate::GameLoop
+
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A report of what happened during a single tick of the game loop.
+///
+/// See [`GameLoop::tick_report`].
+///
+/// [`GameLoop::tick_report`]: crate::GameLoop::tick_report
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TickReport {
+    /// The amount of times the game state was updated during the tick.
+    pub updates: usize,
+
+    /// Whether the game state was rendered during the tick.
+    ///
+    /// A tick doesn't necessarily render, for example when rendering is
+    /// disabled. See [`TickReport::presented`] to decide whether to swap
+    /// buffers.
+    pub rendered: bool,
+
+    /// Whether the frame rendered during the tick should be presented, for
+    /// example by swapping buffers.
+    ///
+    /// The renderer can veto presenting a frame, see
+    /// [`Renderer::render_outcome`]. A tick that didn't render has nothing to
+    /// present.
+    ///
+    /// [`Renderer::render_outcome`]: crate::Renderer::render_outcome
+    pub presented: bool,
+
+    /// The amount of render errors swallowed during the tick, because they
+    /// happened shortly after a resize.
+    ///
+    /// A tick that swallowed the error of its last render didn't render. See
+    /// [`GameLoop::set_suppress_render_errors`].
+    ///
+    /// [`GameLoop::set_suppress_render_errors`]: crate::GameLoop::set_suppress_render_errors
+    pub suppressed_render_errors: usize,
+
+    /// Why the tick played out the way it did.
+    pub reason: TickReason,
+
+    /// The label of the game loop that ran the tick, if any.
+    ///
+    /// See [`GameLoop::set_label`].
+    ///
+    /// [`GameLoop::set_label`]: crate::GameLoop::set_label
+    pub label: Option<Arc<str>>,
+}
+
+impl TickReport {
+    /// Write the report as a single line of comma-separated values, see
+    /// [`GameLoop::set_trace_writer`].
+    ///
+    /// [`GameLoop::set_trace_writer`]: crate::GameLoop::set_trace_writer
+    pub(crate) fn write_trace_line(&self, writer: &mut dyn Write) -> io::Result<()> {
+        let (reason, remaining) = match self.reason {
+            TickReason::UpdatedAndRendered => ("updated_and_rendered", Duration::default()),
+            TickReason::UpdatedOnly => ("updated_only", Duration::default()),
+            TickReason::RenderedOnly(remaining) => ("rendered_only", remaining),
+            TickReason::CappedCatchUp => ("capped_catch_up", Duration::default()),
+            TickReason::Paused => ("paused", Duration::default()),
+        };
+
+        writeln!(
+            writer,
+            "{},{},{},{},{}",
+            self.updates,
+            self.rendered,
+            self.presented,
+            reason,
+            remaining.as_nanos()
+        )
+    }
+}
+
+/// The reason a tick of the game loop played out the way it did.
+///
+/// This is meant to aid in debugging timing behaviour, such as "why didn't my
+/// update run?".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickReason {
+    /// The game state was updated at least once, and the rendered frame was
+    /// presented.
+    UpdatedAndRendered,
+
+    /// The game state was updated at least once, but the render was skipped,
+    /// or the renderer vetoed presenting the rendered frame.
+    ///
+    /// See [`TickReport::rendered`] and [`TickReport::presented`].
+    UpdatedOnly,
+
+    /// Not enough time accumulated to update the game state.
+    ///
+    /// Contains the accumulated time remaining after the tick, which is less
+    /// than the update interval.
+    RenderedOnly(Duration),
+
+    /// The maximum amount of updates per tick was reached, before all
+    /// accumulated time was drained.
+    ///
+    /// See [`GameLoop::set_max_updates_per_tick`].
+    ///
+    /// [`GameLoop::set_max_updates_per_tick`]: crate::GameLoop::set_max_updates_per_tick
+    CappedCatchUp,
+
+    /// The game loop is paused, so no time accumulated.
+    ///
+    /// See [`GameLoop::pause`].
+    ///
+    /// [`GameLoop::pause`]: crate::GameLoop::pause
+    Paused,
+}
+
+impl Default for TickReason {
+    fn default() -> Self {
+        TickReason::RenderedOnly(Duration::default())
+    }
+}
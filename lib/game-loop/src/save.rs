@@ -0,0 +1,208 @@
+//! The module implementing `serde` support for the [`GameLoop`], to persist
+//! the game loop as part of a save game.
+
+use crate::{
+    GameLoop, GameLoopBuilder, InvariantViolation, Remainder, Renderer, TimestepMode, Updater,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::time::Duration;
+
+/// The persisted parts of a game loop, owning (or borrowing) the game state.
+///
+/// Anything tied to the running process, such as the clock, the stop handle,
+/// or callbacks, isn't persisted. Neither is the start of the previous tick,
+/// so a restored game loop doesn't catch up with the time passed since the
+/// game loop was saved.
+#[derive(Serialize, Deserialize)]
+struct SavedLoop<S> {
+    /// The game state.
+    state: S,
+
+    /// See [`GameLoop::accumulated_time`].
+    accumulated_time: Duration,
+
+    /// See [`GameLoop::update_interval`].
+    update_interval: Duration,
+
+    /// See [`GameLoop::update_sequence`].
+    update_sequence: u64,
+
+    /// See [`GameLoop::simulation_time`].
+    simulation_time: Duration,
+
+    /// See [`GameLoop::set_update_substeps`].
+    update_substeps: u32,
+
+    /// See [`GameLoop::set_max_interpolation`].
+    max_interpolation: Remainder,
+
+    /// See [`GameLoop::set_max_updates_per_tick`].
+    max_updates_per_tick: Option<usize>,
+
+    /// See [`GameLoop::set_render_every`].
+    render_every: usize,
+
+    /// See [`GameLoopBuilder::render_on_exit`].
+    render_on_exit: bool,
+
+    /// See [`GameLoopBuilder::deterministic`].
+    deterministic: bool,
+
+    /// See [`GameLoop::pause`].
+    paused: bool,
+
+    /// See [`GameLoop::set_timestep_mode`].
+    ///
+    /// Defaults to the fixed timestep, for saves predating this field.
+    #[serde(default)]
+    timestep_mode: TimestepMode,
+
+    /// See [`GameLoop::set_max_frame_time`].
+    #[serde(default)]
+    max_frame_time: Option<Duration>,
+}
+
+impl<T> Serialize for GameLoop<T>
+where
+    T: Updater + Renderer + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SavedLoop {
+            state: &self.state,
+            accumulated_time: self.accumulated_time,
+            update_interval: self.update_interval,
+            update_sequence: self.update_sequence,
+            simulation_time: self.simulation_time,
+            update_substeps: self.update_substeps,
+            max_interpolation: self.max_interpolation,
+            max_updates_per_tick: self.max_updates_per_tick,
+            render_every: self.render_every,
+            render_on_exit: self.render_on_exit,
+            deterministic: self.deterministic,
+            paused: self.paused,
+            timestep_mode: self.timestep_mode,
+            max_frame_time: self.max_frame_time,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// A restored game loop uses the default clock. Its first tick doesn't
+/// accumulate any time, just like the first tick of a new game loop, so no
+/// spurious catch-up happens after loading a save game.
+impl<'de, T> Deserialize<'de> for GameLoop<T>
+where
+    T: Updater + Renderer + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let saved = SavedLoop::<T>::deserialize(deserializer)?;
+
+        // A corrupt save game must not produce a game loop that never finishes
+        // ticking, or panics on its first update.
+        if saved.update_interval == Duration::default() {
+            return Err(serde::de::Error::custom(
+                InvariantViolation::ZeroUpdateInterval,
+            ));
+        }
+        if saved.update_substeps == 0 {
+            return Err(serde::de::Error::custom(
+                "the amount of update substeps must be positive",
+            ));
+        }
+
+        let mut game_loop = GameLoopBuilder::new()
+            .render_on_exit(saved.render_on_exit)
+            .deterministic(saved.deterministic)
+            .build(saved.state)
+            .map_err(serde::de::Error::custom)?;
+        game_loop.accumulated_time = saved.accumulated_time;
+        game_loop.update_interval = saved.update_interval;
+        game_loop.update_sequence = saved.update_sequence;
+        game_loop.simulation_time = saved.simulation_time;
+        game_loop.update_substeps = saved.update_substeps;
+        game_loop.max_interpolation = saved.max_interpolation;
+        game_loop.max_updates_per_tick = saved.max_updates_per_tick;
+        game_loop.render_every = saved.render_every;
+        game_loop.paused = saved.paused;
+        game_loop.timestep_mode = saved.timestep_mode;
+        game_loop.max_frame_time = saved.max_frame_time;
+
+        Ok(game_loop)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct State {
+        position: u32,
+    }
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.position += 1;
+            Ok(())
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_game_loop_serde_round_trip() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.add_accumulated_time(Duration::from_millis(25));
+        game_loop.tick().unwrap();
+
+        let saved = serde_json::to_string(&game_loop).unwrap();
+        let mut restored: GameLoop<State> = serde_json::from_str(&saved).unwrap();
+
+        assert!(!restored.has_ticked());
+        assert_eq!(restored.state().position, 2);
+        assert_eq!(restored.accumulated_time(), game_loop.accumulated_time());
+        assert_eq!(restored.update_sequence(), 2);
+    }
+
+    #[test]
+    fn test_game_loop_serde_timestep() {
+        let mut game_loop = GameLoop::new(State::default());
+        game_loop.set_timestep_mode(TimestepMode::Variable);
+        game_loop.set_max_frame_time(Some(Duration::from_millis(250)));
+
+        let saved = serde_json::to_string(&game_loop).unwrap();
+        let restored: GameLoop<State> = serde_json::from_str(&saved).unwrap();
+
+        assert_eq!(restored.timestep_mode, TimestepMode::Variable);
+        assert_eq!(restored.max_frame_time, Some(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn test_game_loop_serde_invalid() {
+        let game_loop = GameLoop::new(State::default());
+        let saved = serde_json::to_value(&game_loop).unwrap();
+
+        let mut corrupt = saved.clone();
+        corrupt["update_substeps"] = 0.into();
+        assert!(serde_json::from_value::<GameLoop<State>>(corrupt).is_err());
+
+        let mut corrupt = saved;
+        corrupt["update_interval"] = serde_json::json!({ "secs": 0, "nanos": 0 });
+        assert!(serde_json::from_value::<GameLoop<State>>(corrupt).is_err());
+    }
+}
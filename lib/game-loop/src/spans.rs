@@ -0,0 +1,172 @@
+//! The module emitting the spans of the [`GameLoop`] through the `tracing`
+//! crate.
+//!
+//! [`GameLoop`]: crate::GameLoop
+
+use crate::{Remainder, Tick};
+use std::convert::TryFrom;
+use std::time::Duration;
+use tracing::field::{self, Empty};
+use tracing::Span;
+
+/// The span covering a single tick.
+///
+/// * `updates`: the amount of updates run by the tick.
+/// * `rendered`: whether the tick rendered the game state.
+/// * `frame_time_ns`: the time measured since the previous tick, if any.
+pub(crate) fn tick_span() -> Span {
+    tracing::debug_span!(
+        "tick",
+        updates = Empty,
+        rendered = Empty,
+        frame_time_ns = Empty
+    )
+}
+
+/// Record the outcome of the given tick in the given span of that tick.
+///
+/// The span is passed explicitly, as the current span is only the span of the
+/// tick as long as no other span is entered in-between.
+pub(crate) fn record_tick(span: &Span, tick: &Tick) {
+    let _ = span.record(
+        "updates",
+        &u64::try_from(tick.updates).unwrap_or(u64::max_value()),
+    );
+    let _ = span.record("rendered", &tick.rendered);
+    if let Some(frame_time) = tick.frame_time {
+        let frame_time = u64::try_from(frame_time.as_nanos()).unwrap_or(u64::max_value());
+        let _ = span.record("frame_time_ns", &frame_time);
+    }
+}
+
+/// The span covering a single update, nested in the span of its tick.
+///
+/// * `duration_ns`: the time the update took, unless the game loop is
+///   deterministic.
+pub(crate) fn update_span(sequence: u64) -> Span {
+    tracing::trace_span!("update", sequence, duration_ns = Empty)
+}
+
+/// The span covering a single render, nested in the span of its tick.
+///
+/// * `duration_ns`: the time the render took, unless the game loop is
+///   deterministic.
+pub(crate) fn render_span(remainder: Remainder) -> Span {
+    tracing::trace_span!(
+        "render",
+        remainder = field::debug(remainder),
+        duration_ns = Empty
+    )
+}
+
+/// Record the time an update or render took in the given span of that update
+/// or render.
+pub(crate) fn record_duration(span: &Span, time: Duration) {
+    let time = u64::try_from(time.as_nanos()).unwrap_or(u64::max_value());
+    let _ = span.record("duration_ns", &time);
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use crate::{GameLoop, Remainder, Renderer, Updater};
+    use std::convert::TryFrom;
+    use std::fmt;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// The name and recorded fields of a captured span.
+    type Captured = (String, Vec<(String, String)>);
+
+    #[derive(Debug, Default)]
+    struct Capture {
+        spans: Arc<Mutex<Vec<Captured>>>,
+        next_id: AtomicU64,
+    }
+
+    struct Fields<'a>(&'a mut Vec<(String, String)>);
+
+    impl Visit for Fields<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .push((field.name().to_owned(), format!("{:?}", value)));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = vec![];
+            span.record(&mut Fields(&mut fields));
+            self.spans
+                .lock()
+                .unwrap()
+                .push((span.metadata().name().to_owned(), fields));
+
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let index = usize::try_from(span.into_u64() - 1).unwrap();
+            if let Some((_, fields)) = self.spans.lock().unwrap().get_mut(index) {
+                values.record(&mut Fields(fields));
+            }
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[derive(Debug, Default)]
+    struct State;
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_spans_per_tick() {
+        let capture = Capture::default();
+        let spans = Arc::clone(&capture.spans);
+        let mut game_loop = GameLoop::new(State);
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+
+        tracing::subscriber::with_default(capture, || game_loop.tick().unwrap());
+
+        let spans = spans.lock().unwrap();
+        let names: Vec<_> = spans.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["tick", "update", "update", "render"]);
+
+        let (_, fields) = &spans[0];
+        assert!(fields.contains(&("updates".to_owned(), "2".to_owned())));
+        assert!(fields.contains(&("rendered".to_owned(), "true".to_owned())));
+
+        for (_, fields) in &spans[1..] {
+            assert!(fields.iter().any(|(name, _)| name == "duration_ns"));
+        }
+    }
+}
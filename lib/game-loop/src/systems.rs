@@ -0,0 +1,333 @@
+//! The module containing a minimal scheduler for game states composed of
+//! multiple systems.
+
+use crate::Updater;
+use std::time::Duration;
+
+/// A container of systems, updated one after the other, in a defined order.
+///
+/// Every system is an [`Updater`] of its own, identified by a name. The
+/// container implements [`Updater`] itself, so it can be used as (part of)
+/// the game state, and the order of the systems can be changed at runtime.
+///
+/// Systems can declare the resources they read and write (see
+/// [`Systems::add_with_access`]), to model running them in parallel, while
+/// staying single-threaded and deterministic: a system reading a resource
+/// runs after every system writing it, and systems writing the same resource
+/// run in the order they were added. Systems without dependencies between
+/// them keep their order.
+#[derive(Debug)]
+pub struct Systems<E>
+where
+    E: std::error::Error,
+{
+    /// The named systems, and the resources they access, in the order they
+    /// were added.
+    systems: Vec<(String, Box<dyn Updater<Error = E>>, Access)>,
+
+    /// The indices of the systems, in the order they are updated.
+    ///
+    /// The order is resolved whenever the systems change, and reused by every
+    /// update.
+    order: Vec<usize>,
+}
+
+/// The resources a system reads and writes.
+#[derive(Debug, Clone, Default)]
+struct Access {
+    /// The names of the resources the system reads.
+    reads: Vec<String>,
+
+    /// The names of the resources the system writes.
+    writes: Vec<String>,
+}
+
+impl Access {
+    /// Whether the system with this access has to run before the system with
+    /// the `other` access, added after it.
+    fn precedes(&self, other: &Self, added_before: bool) -> bool {
+        self.writes.iter().any(|resource| {
+            other.reads.contains(resource) || (added_before && other.writes.contains(resource))
+        })
+    }
+}
+
+impl<E> Default for Systems<E>
+where
+    E: std::error::Error,
+{
+    fn default() -> Self {
+        Self {
+            systems: vec![],
+            order: vec![],
+        }
+    }
+}
+
+impl<E> Systems<E>
+where
+    E: std::error::Error,
+{
+    /// Create an empty container.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a system with the given name, to be updated after all existing
+    /// systems.
+    ///
+    /// Any existing system with the same name is replaced, keeping its place
+    /// in the order.
+    pub fn add<N, S>(&mut self, name: N, system: S)
+    where
+        N: Into<String>,
+        S: Updater<Error = E> + 'static,
+    {
+        self.add_with_access(name, system, &[], &[]);
+    }
+
+    /// Add a system with the given name, that reads and writes the given
+    /// resources, to be updated after all existing systems it doesn't depend
+    /// on.
+    ///
+    /// Resources are identified by name, and only used to order the systems,
+    /// see [`Systems`]. Any existing system with the same name is replaced,
+    /// including the resources it accesses. If the dependencies between the
+    /// systems form a cycle, the systems in the cycle are updated in the order
+    /// they were added.
+    pub fn add_with_access<N, S>(&mut self, name: N, system: S, reads: &[&str], writes: &[&str])
+    where
+        N: Into<String>,
+        S: Updater<Error = E> + 'static,
+    {
+        let name = name.into();
+        let system: Box<dyn Updater<Error = E>> = Box::new(system);
+        let access = Access {
+            reads: reads.iter().map(|&resource| resource.to_owned()).collect(),
+            writes: writes.iter().map(|&resource| resource.to_owned()).collect(),
+        };
+
+        match self.position(&name) {
+            Some(index) => {
+                if let Some(existing) = self.systems.get_mut(index) {
+                    existing.1 = system;
+                    existing.2 = access;
+                }
+            }
+            None => self.systems.push((name, system, access)),
+        }
+
+        self.resolve_order();
+    }
+
+    /// Remove the system with the given name, returning it, if it exists.
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Updater<Error = E>>> {
+        let index = self.position(name)?;
+        let system = self.systems.remove(index).1;
+        self.resolve_order();
+
+        Some(system)
+    }
+
+    /// Move the system with the given name to the given place in the order,
+    /// shifting the systems after it.
+    ///
+    /// Returns `false` if no system with the given name exists. An index past
+    /// the end of the order moves the system to the end. The dependencies
+    /// between the systems (see [`Systems::add_with_access`]) take precedence
+    /// over this order.
+    pub fn reorder(&mut self, name: &str, index: usize) -> bool {
+        let system = match self.position(name) {
+            Some(current) => self.systems.remove(current),
+            None => return false,
+        };
+
+        let index = index.min(self.systems.len());
+        self.systems.insert(index, system);
+        self.resolve_order();
+
+        true
+    }
+
+    /// The names of the systems, in the order they are updated.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.order
+            .iter()
+            .filter_map(move |&index| self.systems.get(index).map(|(name, _, _)| name.as_str()))
+    }
+
+    /// The place of the system with the given name in the order the systems
+    /// were added.
+    fn position(&self, name: &str) -> Option<usize> {
+        self.systems
+            .iter()
+            .position(|(existing, _, _)| existing == name)
+    }
+
+    /// Sort the systems by their dependencies, keeping the order in which they
+    /// were added where they don't depend on each other.
+    fn resolve_order(&mut self) {
+        let systems = &self.systems;
+        let access = |index: usize| systems.get(index).map(|(_, _, access)| access);
+        let precedes = |before: usize, after: usize| match (access(before), access(after)) {
+            (Some(first), Some(second)) => {
+                before != after && first.precedes(second, before < after)
+            }
+            _ => false,
+        };
+
+        let mut order = Vec::with_capacity(systems.len());
+        while order.len() < systems.len() {
+            let ready = {
+                let pending = |index: &usize| !order.contains(index);
+
+                (0..systems.len())
+                    .filter(pending)
+                    .find(|&after| {
+                        !(0..systems.len())
+                            .filter(pending)
+                            .any(|before| precedes(before, after))
+                    })
+                    // The pending systems form a cycle, break it in the order
+                    // the systems were added.
+                    .or_else(|| (0..systems.len()).find(pending))
+            };
+
+            match ready {
+                Some(index) => order.push(index),
+                None => break,
+            }
+        }
+
+        self.order = order;
+    }
+}
+
+impl<E> Updater for Systems<E>
+where
+    E: std::error::Error,
+{
+    type Error = E;
+
+    fn update(&mut self) -> Result<(), Self::Error> {
+        for &index in &self.order {
+            if let Some((_, system, _)) = self.systems.get_mut(index) {
+                system.update()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn update_step(&mut self, step: Duration) -> Result<(), Self::Error> {
+        for &index in &self.order {
+            if let Some((_, system, _)) = self.systems.get_mut(index) {
+                system.update_step(step)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn update_substep(
+        &mut self,
+        step: Duration,
+        substep: u32,
+        substeps: u32,
+    ) -> Result<(), Self::Error> {
+        for &index in &self.order {
+            if let Some((_, system, _)) = self.systems.get_mut(index) {
+                system.update_substep(step, substep, substeps)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_pause(&mut self) {
+        for &index in &self.order {
+            if let Some((_, system, _)) = self.systems.get_mut(index) {
+                system.on_pause();
+            }
+        }
+    }
+
+    fn on_resume(&mut self) {
+        for &index in &self.order {
+            if let Some((_, system, _)) = self.systems.get_mut(index) {
+                system.on_resume();
+            }
+        }
+    }
+
+    fn interpolation_corrected(&self) -> bool {
+        self.systems
+            .iter()
+            .any(|(_, system, _)| system.interpolation_corrected())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    struct System {
+        name: &'static str,
+        log: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Updater for System {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            self.log.borrow_mut().push(self.name);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_systems_update_order() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut systems = Systems::new();
+        for name in &["input", "physics", "audio"] {
+            let log = Rc::clone(&log);
+            systems.add(*name, System { name, log });
+        }
+
+        systems.update().unwrap();
+        assert_eq!(*log.borrow(), vec!["input", "physics", "audio"]);
+
+        log.borrow_mut().clear();
+        assert!(systems.reorder("audio", 0));
+        assert!(systems.remove("input").is_some());
+        systems.update().unwrap();
+
+        assert_eq!(*log.borrow(), vec!["audio", "physics"]);
+        assert!(!systems.reorder("input", 0));
+    }
+
+    #[test]
+    fn test_systems_dependency_order() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut systems = Systems::new();
+        let mut add = |name, reads: &[&str], writes: &[&str]| {
+            let log = Rc::clone(&log);
+            systems.add_with_access(name, System { name, log }, reads, writes);
+        };
+
+        add("render_prep", &["transforms"], &[]);
+        add("audio", &[], &[]);
+        add("physics", &["velocities"], &["transforms"]);
+        add("input", &[], &["velocities"]);
+
+        let order: Vec<_> = systems.names().map(str::to_owned).collect();
+        assert_eq!(order, vec!["audio", "input", "physics", "render_prep"]);
+
+        systems.update().unwrap();
+        assert_eq!(*log.borrow(), order);
+    }
+}
@@ -0,0 +1,82 @@
+//! The module emitting the metrics of the [`GameLoop`] through the `metrics`
+//! crate facade.
+//!
+//! [`GameLoop`]: crate::GameLoop
+
+use crate::TickReport;
+use std::convert::TryFrom;
+
+/// Emit the metrics of a completed tick.
+///
+/// * `game_loop.updates`: a counter of the updates of the game state.
+/// * `game_loop.fps`: a gauge of the ticks per second, if known.
+/// * `game_loop.catch_up`: a histogram of the updates per tick.
+pub(crate) fn record_tick(report: &TickReport, fps: Option<u32>) {
+    let updates = u64::try_from(report.updates).unwrap_or(u64::max_value());
+
+    metrics::counter!("game_loop.updates", updates);
+    metrics::value!("game_loop.catch_up", updates);
+
+    if let Some(fps) = fps {
+        metrics::gauge!("game_loop.fps", i64::from(fps));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use crate::{GameLoop, Remainder, Renderer, Updater};
+    use metrics::{Key, Recorder};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// The updates counted by the capturing recorder, across all tests.
+    static UPDATES: AtomicU64 = AtomicU64::new(0);
+
+    #[derive(Debug)]
+    struct Capture;
+
+    impl Recorder for Capture {
+        fn increment_counter(&self, key: Key, value: u64) {
+            if key.name() == "game_loop.updates" {
+                let _ = UPDATES.fetch_add(value, Ordering::SeqCst);
+            }
+        }
+
+        fn update_gauge(&self, _key: Key, _value: i64) {}
+
+        fn record_histogram(&self, _key: Key, _value: u64) {}
+    }
+
+    #[derive(Debug, Default)]
+    struct State;
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl Renderer for State {
+        type Error = std::io::Error;
+
+        fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_telemetry_counts_updates() {
+        let _ = metrics::set_boxed_recorder(Box::new(Capture));
+        let mut game_loop = GameLoop::deterministic(State);
+
+        // Other tests might tick in parallel, adding to the counter.
+        let before = UPDATES.load(Ordering::SeqCst);
+        for _ in 0..3 {
+            game_loop.tick().unwrap();
+        }
+
+        assert!(UPDATES.load(Ordering::SeqCst) - before >= 3);
+    }
+}
@@ -0,0 +1,161 @@
+//! The module containing helpers to test game states driven by a
+//! [`GameLoop`].
+//!
+//! [`GameLoop`]: crate::GameLoop
+
+use crate::{Remainder, Renderer, Updater};
+use std::any::Any;
+use std::convert::Infallible;
+use std::time::Duration;
+
+/// A wrapper around a game state that only implements [`Updater`], with a
+/// renderer that records the remainder of every render.
+///
+/// This allows testing interpolation logic, such as the remainders produced
+/// by a given sequence of time deltas, without writing a mock renderer.
+#[derive(Debug, Default, Clone)]
+pub struct RecordingRenderer<U>
+where
+    U: Updater,
+{
+    /// The wrapped game state.
+    state: U,
+
+    /// The remainders of all renders, oldest first.
+    remainders: Vec<Remainder>,
+}
+
+impl<U> RecordingRenderer<U>
+where
+    U: Updater,
+{
+    /// Wrap the given game state.
+    pub fn new(state: U) -> Self {
+        Self {
+            state,
+            remainders: vec![],
+        }
+    }
+
+    /// The remainders of all renders so far, oldest first.
+    pub fn remainders(&self) -> &[Remainder] {
+        &self.remainders
+    }
+
+    /// The wrapped game state.
+    pub fn state(&self) -> &U {
+        &self.state
+    }
+
+    /// The wrapped game state, mutably.
+    pub fn state_mut(&mut self) -> &mut U {
+        &mut self.state
+    }
+
+    /// Unwrap the game state.
+    pub fn into_inner(self) -> U {
+        self.state
+    }
+}
+
+impl<U> Updater for RecordingRenderer<U>
+where
+    U: Updater,
+{
+    type Error = U::Error;
+
+    fn update(&mut self) -> Result<(), Self::Error> {
+        self.state.update()
+    }
+
+    fn update_step(&mut self, step: Duration) -> Result<(), Self::Error> {
+        self.state.update_step(step)
+    }
+
+    fn update_substep(
+        &mut self,
+        step: Duration,
+        substep: u32,
+        substeps: u32,
+    ) -> Result<(), Self::Error> {
+        self.state.update_substep(step, substep, substeps)
+    }
+
+    fn update_with<C>(&mut self, step: Duration, ctx: &C) -> Result<(), Self::Error>
+    where
+        C: Any,
+    {
+        self.state.update_with(step, ctx)
+    }
+
+    fn on_pause(&mut self) {
+        self.state.on_pause();
+    }
+
+    fn on_resume(&mut self) {
+        self.state.on_resume();
+    }
+
+    fn interpolation_corrected(&self) -> bool {
+        self.state.interpolation_corrected()
+    }
+
+    fn desired_update_rate(&self) -> Option<u32> {
+        self.state.desired_update_rate()
+    }
+
+    fn is_active(&self) -> bool {
+        self.state.is_active()
+    }
+}
+
+impl<U> Renderer for RecordingRenderer<U>
+where
+    U: Updater,
+{
+    type Error = Infallible;
+
+    fn render(&mut self, remainder: Remainder) -> Result<(), Self::Error> {
+        self.remainders.push(remainder);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{ClockSource, GameLoopBuilder};
+
+    #[derive(Debug, Default)]
+    struct State;
+
+    impl Updater for State {
+        type Error = std::io::Error;
+
+        fn update(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_recording_renderer_captures_remainders() {
+        let mut game_loop = GameLoopBuilder::new()
+            .clock_source(ClockSource::Manual)
+            .build(RecordingRenderer::new(State))
+            .unwrap();
+        game_loop.tick().unwrap();
+
+        for delta in &[5, 10, 2] {
+            game_loop
+                .tick_with_delta(Duration::from_millis(*delta))
+                .unwrap();
+        }
+
+        let remainders = game_loop.state().remainders();
+        assert_eq!(remainders.len(), 4);
+        for (remainder, expected) in remainders.iter().zip(&[0.0, 0.5, 0.5, 0.7]) {
+            assert!((remainder - expected).abs() < 1e-6);
+        }
+    }
+}
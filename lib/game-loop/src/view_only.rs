@@ -0,0 +1,143 @@
+//! The module containing the wrapper to render a game state without updating
+//! it.
+
+use crate::{GameLoop, Remainder, Renderer, Updater};
+use std::convert::Infallible;
+
+/// A wrapper around a game state that only implements [`Renderer`], with an
+/// updater that does nothing.
+///
+/// This allows running render-only game states, such as replay viewers that
+/// render pre-recorded states, see [`GameLoop::view_only`]. Instead of the
+/// remainder computed by the game loop, the wrapped renderer receives the
+/// playback position supplied through [`ViewOnly::set_position`].
+#[derive(Debug, Default, Clone)]
+pub struct ViewOnly<R>
+where
+    R: Renderer,
+{
+    /// The wrapped game state.
+    state: R,
+
+    /// The playback position passed to the renderer as its remainder.
+    position: Remainder,
+}
+
+impl<R> ViewOnly<R>
+where
+    R: Renderer,
+{
+    /// Wrap the given game state, with the playback position at zero.
+    pub fn new(state: R) -> Self {
+        Self {
+            state,
+            position: 0.0,
+        }
+    }
+
+    /// Set the playback position, which is passed to the renderer as its
+    /// remainder.
+    pub fn set_position(&mut self, position: Remainder) {
+        self.position = position;
+    }
+
+    /// The playback position, see [`ViewOnly::set_position`].
+    pub fn position(&self) -> Remainder {
+        self.position
+    }
+
+    /// The wrapped game state.
+    pub fn state(&self) -> &R {
+        &self.state
+    }
+
+    /// The wrapped game state, mutably.
+    pub fn state_mut(&mut self) -> &mut R {
+        &mut self.state
+    }
+
+    /// Unwrap the game state.
+    pub fn into_inner(self) -> R {
+        self.state
+    }
+}
+
+impl<R> Updater for ViewOnly<R>
+where
+    R: Renderer,
+{
+    type Error = Infallible;
+
+    fn update(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<R> Renderer for ViewOnly<R>
+where
+    R: Renderer,
+{
+    type Error = R::Error;
+
+    fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
+        self.state.render(self.position)
+    }
+}
+
+// The constructor is only available for render-only game states, so it can't
+// be part of the main `impl` block of the game loop.
+#[allow(clippy::multiple_inherent_impl)]
+impl<R> GameLoop<ViewOnly<R>>
+where
+    R: Renderer,
+{
+    /// Create a new game loop for a game state that only implements
+    /// [`Renderer`], using the default configuration of [`GameLoopBuilder`].
+    ///
+    /// The game state is wrapped in [`ViewOnly`], and the game loop is paused,
+    /// so that no time accumulates, and every tick renders the game state once,
+    /// at the playback position supplied through [`ViewOnly::set_position`].
+    ///
+    /// [`GameLoopBuilder`]: crate::GameLoopBuilder
+    pub fn view_only(state: R) -> Self {
+        let mut game_loop = Self::new(ViewOnly::new(state));
+        game_loop.pause();
+
+        game_loop
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_unwrap_used)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[derive(Debug, Default)]
+    struct Viewer {
+        rendered: Vec<Remainder>,
+    }
+
+    impl Renderer for Viewer {
+        type Error = std::io::Error;
+
+        fn render(&mut self, remainder: Remainder) -> Result<(), Self::Error> {
+            self.rendered.push(remainder);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_game_loop_view_only() {
+        let mut game_loop = GameLoop::view_only(Viewer::default());
+
+        game_loop.add_accumulated_time(Duration::from_millis(5));
+        game_loop.tick().unwrap();
+        game_loop.state().set_position(0.25);
+        game_loop.add_accumulated_time(Duration::from_millis(20));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.update_sequence(), 0);
+        assert_eq!(game_loop.state().state().rendered, vec![0.0, 0.25]);
+    }
+}
@@ -0,0 +1,133 @@
+//! The module parsing the game configuration from the command line.
+
+use crate::error::GameError;
+use std::time::Duration;
+
+/// The usage of the command line options, shown when they are invalid.
+pub(crate) const USAGE: &str = "\
+usage: rkt [--ups <updates per second>] [--duration <duration>]
+
+  --ups       the amount of times per second the game state is updated,
+              defaults to 100
+  --duration  how long the game runs before exiting by itself, such as
+              `500ms`, `5s` or `2m`, defaults to running until the window is
+              closed";
+
+/// The configuration of the game, as passed on the command line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Config {
+    /// The amount of times per second the game state is updated (`--ups`).
+    pub(crate) updates_per_second: u32,
+
+    /// How long the game runs before exiting by itself (`--duration`).
+    ///
+    /// If none is given, the game runs until the window is closed.
+    pub(crate) duration: Option<Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            updates_per_second: 100,
+            duration: None,
+        }
+    }
+}
+
+impl Config {
+    /// Parse the configuration from the given arguments, excluding the name of
+    /// the binary.
+    ///
+    /// Any option that isn't provided keeps its default value.
+    pub(crate) fn from_args<I, S>(args: I) -> Result<Self, GameError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut config = Self::default();
+        let mut args = args.into_iter().map(Into::into);
+
+        while let Some(arg) = args.next() {
+            let value = args.next().ok_or_else(|| {
+                GameError::InvalidArgument(format!("missing value for `{}`", arg))
+            })?;
+
+            match arg.as_str() {
+                "--ups" => config.updates_per_second = parse_updates_per_second(&value)?,
+                "--duration" => config.duration = Some(parse_duration(&value)?),
+                _ => {
+                    return Err(GameError::InvalidArgument(format!(
+                        "unknown option `{}`",
+                        arg
+                    )))
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Parse a (non-zero) amount of updates per second.
+fn parse_updates_per_second(value: &str) -> Result<u32, GameError> {
+    match value.parse() {
+        Ok(0) | Err(_) => Err(GameError::InvalidArgument(format!(
+            "`{}` is not a valid amount of updates per second",
+            value
+        ))),
+        Ok(updates_per_second) => Ok(updates_per_second),
+    }
+}
+
+/// Parse a duration such as `500ms`, `5s` or `2m`. Without a unit, the value
+/// is interpreted as seconds.
+fn parse_duration(value: &str) -> Result<Duration, GameError> {
+    let invalid = || GameError::InvalidArgument(format!("`{}` is not a valid duration", value));
+
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or_else(|| value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount.parse().map_err(|_| invalid())?;
+
+    match unit {
+        "ms" => Ok(Duration::from_millis(amount)),
+        "" | "s" => Ok(Duration::from_secs(amount)),
+        "m" => Ok(Duration::from_secs(amount * 60)),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_from_args() {
+        let config = Config::from_args(vec!["--ups", "60", "--duration", "5s"]).unwrap();
+
+        assert_eq!(config.updates_per_second, 60);
+        assert_eq!(config.duration, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_config_from_args_durations() {
+        let duration = |value| {
+            Config::from_args(vec!["--duration", value])
+                .unwrap()
+                .duration
+        };
+
+        assert_eq!(duration("250ms"), Some(Duration::from_millis(250)));
+        assert_eq!(duration("3"), Some(Duration::from_secs(3)));
+        assert_eq!(duration("2m"), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn test_config_from_invalid_args() {
+        assert!(Config::from_args(vec!["--ups"]).is_err());
+        assert!(Config::from_args(vec!["--ups", "0"]).is_err());
+        assert!(Config::from_args(vec!["--duration", "5h"]).is_err());
+        assert!(Config::from_args(vec!["--fps", "60"]).is_err());
+    }
+}
@@ -6,6 +6,9 @@ use std::fmt;
 /// All possible error states the game can end up in.
 #[derive(Debug)]
 pub(crate) enum GameError {
+    /// The game was started with an invalid command-line argument.
+    InvalidArgument(String),
+
     Unknown,
 }
 
@@ -14,7 +17,7 @@ impl Error for GameError {
         use GameError::*;
 
         match self {
-            Unknown => None,
+            InvalidArgument(_) | Unknown => None,
         }
     }
 }
@@ -24,6 +27,7 @@ impl fmt::Display for GameError {
         use GameError::*;
 
         match self {
+            InvalidArgument(message) => write!(f, "invalid argument: {}", message),
             Unknown => f.write_str("unknown!"),
         }
     }
@@ -1,6 +1,7 @@
 #![allow(clippy::len_zero)]
 #![allow(clippy::many_single_char_names)]
 
+mod config;
 mod error;
 mod state;
 
@@ -16,7 +17,7 @@ use gfx_backend_vulkan as back;
 
 use arrayvec::ArrayVec;
 use core::mem::{size_of, ManuallyDrop};
-use game_loop::GameLoop;
+use game_loop::GameLoopBuilder;
 use gfx_hal::{
     adapter::{Adapter, MemoryTypeId, PhysicalDevice},
     buffer::Usage as BufferUsage,
@@ -921,6 +922,14 @@ fn do_the_render(hal_state: &mut HalState, local_state: &LocalState) -> Result<(
 fn main() {
     env_logger::init();
 
+    let config = match crate::config::Config::from_args(std::env::args().skip(1)) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, crate::config::USAGE);
+            std::process::exit(2);
+        }
+    };
+
     let mut winit_state = WinitState::default();
 
     let mut hal_state = match HalState::new(&winit_state.window) {
@@ -941,9 +950,19 @@ fn main() {
     };
 
     let state = crate::state::GameState::default();
-    let mut game_loop = GameLoop::new(state);
+    let mut game_loop = GameLoopBuilder::new()
+        .updates_per_second(config.updates_per_second)
+        .build(state)
+        .expect("Invalid update rate!");
 
+    let started_at = Instant::now();
     loop {
+        if let Some(duration) = config.duration {
+            if started_at.elapsed() >= duration {
+                break;
+            }
+        }
+
         if let Err(err) = game_loop.tick() {
             error!("Game Loop Error: {:?}", err);
         }
@@ -971,4 +990,10 @@ fn main() {
             };
         }
     }
+
+    info!(
+        "Game state was updated {} times, and rendered {} times.",
+        game_loop.state().updates(),
+        game_loop.state().renders()
+    );
 }
@@ -1,7 +1,7 @@
 //! The module keeping track of the state of the game.
 
 use crate::error::GameError;
-use game_loop::{Renderer, Updater};
+use game_loop::{Remainder, Renderer, Updater};
 
 /// The state of the game.
 #[derive(Debug, Default)]
@@ -10,6 +10,18 @@ pub(crate) struct GameState {
     renders: usize,
 }
 
+impl GameState {
+    /// The amount of times the game state was updated.
+    pub(crate) fn updates(&self) -> usize {
+        self.updates
+    }
+
+    /// The amount of times the game state was rendered.
+    pub(crate) fn renders(&self) -> usize {
+        self.renders
+    }
+}
+
 impl Updater for GameState {
     type Error = GameError;
 
@@ -22,8 +34,33 @@ impl Updater for GameState {
 impl Renderer for GameState {
     type Error = GameError;
 
-    fn render(&mut self, _remainder: f32) -> Result<(), Self::Error> {
+    fn render(&mut self, _remainder: Remainder) -> Result<(), Self::Error> {
         self.renders += 1;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_loop::{GameLoopBuilder, ManualClock};
+    use std::time::Duration;
+
+    #[test]
+    fn test_game_state_counts() {
+        let clock = ManualClock::new();
+        let mut game_loop = GameLoopBuilder::new()
+            .clock(clock.clone())
+            .build(GameState::default())
+            .unwrap();
+
+        game_loop.tick().unwrap();
+        clock.advance(Duration::from_millis(20));
+        game_loop.tick().unwrap();
+        clock.advance(Duration::from_millis(15));
+        game_loop.tick().unwrap();
+
+        assert_eq!(game_loop.state().updates(), 3);
+        assert_eq!(game_loop.state().renders(), 3);
+    }
+}